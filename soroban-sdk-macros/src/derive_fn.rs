@@ -9,7 +9,7 @@ use syn::{
     Error, FnArg, Ident, Pat, PatIdent, PatType, ReturnType, Type, TypePath, TypeReference,
 };
 
-use crate::map_type::map_type;
+use crate::map_type::{map_type, sdk_type_specs};
 
 #[allow(clippy::too_many_lines)]
 pub fn derive_fn(
@@ -141,6 +141,10 @@ pub fn derive_fn(
         quote! { #[export_name = #wrap_export_name] }
     };
     let slice_args: Vec<TokenStream2> = (0..wrap_args.len()).map(|n| quote! { args[#n] }).collect();
+    let output_ty = match output {
+        ReturnType::Type(_, ty) => quote! { #ty },
+        ReturnType::Default => quote! { () },
+    };
     let use_trait = if let Some(t) = trait_ident {
         quote! { use super::#t }
     } else {
@@ -148,6 +152,16 @@ pub fn derive_fn(
     };
 
     // Generated code spec.
+    let link_section = if let Some(cfg_feature) = feature {
+        quote! { #[cfg_attr(all(target_family = "wasm", feature = #cfg_feature), link_section = "contractspecv0")] }
+    } else {
+        quote! { #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")] }
+    };
+    let sdk_specs = sdk_type_specs(
+        &wrap_export_name,
+        spec_args.iter().chain(spec_result.iter()),
+        &link_section,
+    );
     let spec_entry = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
         name: wrap_export_name.clone().try_into().unwrap(),
         input_types: spec_args.try_into().unwrap(),
@@ -157,11 +171,6 @@ pub fn derive_fn(
     let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
     let spec_xdr_len = spec_xdr.len();
     let spec_ident = format_ident!("__SPEC_XDR_{}", ident.to_string().to_uppercase());
-    let link_section = if let Some(cfg_feature) = feature {
-        quote! { #[cfg_attr(all(target_family = "wasm", feature = #cfg_feature), link_section = "contractspecv0")] }
-    } else {
-        quote! { #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")] }
-    };
 
     // Generated code.
     Ok(quote! {
@@ -169,6 +178,8 @@ pub fn derive_fn(
         #link_section
         pub static #spec_ident: [u8; #spec_xdr_len] = *#spec_xdr_lit;
 
+        #sdk_specs
+
         #[doc(hidden)]
         #[deprecated(note = #deprecated_note)]
         pub mod #hidden_mod_ident {
@@ -214,6 +225,17 @@ pub fn derive_fn(
                 e.invoke_contract(contract_id, &Symbol::from_str(#wrap_export_name), args)
             }
 
+            pub fn try_invoke(
+                e: &soroban_sdk::Env,
+                contract_id: &soroban_sdk::BytesN<32>,
+                #(#invoke_args),*
+            ) -> core::result::Result<#output_ty, soroban_sdk::Status> {
+                use soroban_sdk::{EnvVal, IntoVal, Symbol, Vec};
+                let mut args: Vec<EnvVal> = Vec::new(e);
+                #(args.push(#invoke_idents.clone().into_env_val(e));)*
+                e.try_invoke_contract(contract_id, &Symbol::from_str(#wrap_export_name), args)
+            }
+
             #[cfg(feature = "testutils")]
             #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
             pub fn invoke_xdr(
@@ -238,13 +260,15 @@ pub fn derive_contract_function_set<'a>(
     ty: &Box<Type>,
     methods: impl Iterator<Item = &'a syn::ImplItemMethod>,
 ) -> TokenStream2 {
-    let (idents, wrap_idents): (Vec<_>, Vec<_>) = methods
+    let (idents, wrap_idents, spec_idents): (Vec<_>, Vec<_>, Vec<_>) = methods
         .map(|m| {
             let ident = format!("{}", m.sig.ident);
             let wrap_ident = format_ident!("__{}", m.sig.ident);
-            (ident, wrap_ident)
+            let spec_ident = format_ident!("__SPEC_XDR_{}", ident.to_uppercase());
+            (ident, wrap_ident, spec_ident)
         })
         .multiunzip();
+    let spec_len = spec_idents.len();
     quote! {
         #[cfg(any(test, feature = "testutils"))]
         impl soroban_sdk::testutils::ContractFunctionSet for #ty {
@@ -265,5 +289,13 @@ pub fn derive_contract_function_set<'a>(
                 }
             }
         }
+
+        #[cfg(any(test, feature = "testutils"))]
+        impl soroban_sdk::testutils::ContractFunctionSpec for #ty {
+            fn spec_xdrs(&self) -> &'static [&'static [u8]] {
+                static SPEC_XDRS: [&[u8]; #spec_len] = [#(&#spec_idents),*];
+                &SPEC_XDRS
+            }
+        }
     }
 }
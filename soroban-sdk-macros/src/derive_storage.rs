@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Attribute, DataEnum, Error, Fields, Ident, Type, Visibility};
+
+/// Generates typed accessors for each variant of a storage key enum, tying
+/// the key to the type given in the variant's `#[value(T)]` attribute.
+///
+/// The `#[value(T)]` attributes are removed from the variants, so that the
+/// enum can be emitted without them.
+pub fn derive_storage_enum(
+    vis: &Visibility,
+    enum_ident: &Ident,
+    data: &mut DataEnum,
+) -> TokenStream2 {
+    // Collect errors as they are encountered and emit them at the end.
+    let mut errors = Vec::<Error>::new();
+
+    let accessors: Vec<_> = data
+        .variants
+        .iter_mut()
+        .filter_map(|v| {
+            let (value_attrs, attrs): (Vec<Attribute>, Vec<Attribute>) =
+                v.attrs.drain(..).partition(|a| a.path.is_ident("value"));
+            v.attrs = attrs;
+
+            let ident = &v.ident;
+            let value_ty: Type = match value_attrs.as_slice() {
+                [a] => match a.parse_args() {
+                    Ok(t) => t,
+                    Err(e) => {
+                        errors.push(e);
+                        return None;
+                    }
+                },
+                [] => {
+                    errors.push(Error::new(
+                        ident.span(),
+                        "missing #[value(T)] attribute on storage key",
+                    ));
+                    return None;
+                }
+                [_, a, ..] => {
+                    errors.push(Error::new(
+                        a.span(),
+                        "multiple #[value(T)] attributes on storage key",
+                    ));
+                    return None;
+                }
+            };
+
+            let (key_params, key) = match &v.fields {
+                Fields::Unit => (None, quote! { #enum_ident::#ident }),
+                Fields::Unnamed(f) if f.unnamed.len() == 1 => {
+                    let key_ty = &f.unnamed[0].ty;
+                    (
+                        Some(quote! { key: &#key_ty }),
+                        quote! { #enum_ident::#ident(key.clone()) },
+                    )
+                }
+                _ => {
+                    errors.push(Error::new(
+                        v.fields.span(),
+                        "storage keys must have zero or one unnamed field",
+                    ));
+                    return None;
+                }
+            };
+            let key_params: Vec<_> = key_params.into_iter().collect();
+
+            let name = snake_case(&ident.to_string());
+            let has_ident = format_ident!("has_{}", name);
+            let get_ident = format_ident!("get_{}", name);
+            let set_ident = format_ident!("set_{}", name);
+            let remove_ident = format_ident!("remove_{}", name);
+
+            Some(quote! {
+                #vis fn #has_ident(env: &soroban_sdk::Env, #(#key_params,)*) -> bool {
+                    env.contract_data().has(#key)
+                }
+
+                #vis fn #get_ident(env: &soroban_sdk::Env, #(#key_params,)*) -> Option<#value_ty> {
+                    env.contract_data().get::<_, #value_ty>(#key).map(|v| v.unwrap())
+                }
+
+                #vis fn #set_ident(env: &soroban_sdk::Env, #(#key_params,)* val: &#value_ty) {
+                    env.contract_data().set(#key, val.clone());
+                }
+
+                #vis fn #remove_ident(env: &soroban_sdk::Env, #(#key_params,)*) {
+                    env.contract_data().remove(#key);
+                }
+            })
+        })
+        .collect();
+
+    // If errors have occurred, render them instead.
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return quote! { #(#compile_errors)* };
+    }
+
+    quote! {
+        impl #enum_ident {
+            #(#accessors)*
+        }
+    }
+}
+
+fn snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -1,25 +1,30 @@
 use itertools::MultiUnzip;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use syn::{DataEnum, DataStruct, Error, Ident, Visibility};
+use syn::{DataEnum, DataStruct, Error, Ident, Variant, Visibility};
 
 use stellar_xdr::{
     ScSpecEntry, ScSpecTypeDef, ScSpecUdtStructFieldV0, ScSpecUdtStructV0, ScSpecUdtUnionCaseV0,
     ScSpecUdtUnionV0, VecM, WriteXdr,
 };
 
-use crate::map_type::map_type;
+use crate::map_type::{map_type, sdk_type_specs};
 
 // TODO: Add field attribute for including/excluding fields in types.
 // TODO: Better handling of partial types and types without all their fields and
 // types with private fields.
 
-pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> TokenStream2 {
+pub fn derive_type_struct(
+    ident: &Ident,
+    data: &DataStruct,
+    spec: bool,
+    version: Option<u32>,
+) -> TokenStream2 {
     // Collect errors as they are encountered and emit them at the end.
     let mut errors = Vec::<Error>::new();
 
     let fields = &data.fields;
-    let (spec_fields, try_froms, intos, try_from_xdrs, into_xdrs): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fields
+    let (spec_fields, try_froms, intos, try_from_xdrs, into_xdrs, arbitraries): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fields
         .iter()
         .filter(|f| matches!(f.vis, Visibility::Public(_)))
         .enumerate()
@@ -70,7 +75,10 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
                     val: (&self.#ident).try_into().map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
                 }
             };
-            (spec_field, try_from, into, try_from_xdr, into_xdr)
+            let arbitrary = quote! {
+                #ident: soroban_sdk::testutils::arbitrary::ArbitraryVal::arbitrary_val(env, u)?
+            };
+            (spec_field, try_from, into, try_from_xdr, into_xdr, arbitrary)
         })
         .multiunzip();
 
@@ -80,8 +88,26 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
         return quote! { #(#compile_errors)* };
     }
 
+    // Versioned types have a _version field holding the version, so that
+    // values built from the spec include it.
+    let mut spec_fields = spec_fields;
+    if version.is_some() {
+        spec_fields.insert(
+            0,
+            ScSpecUdtStructFieldV0 {
+                name: "_version".try_into().unwrap(),
+                type_: ScSpecTypeDef::U32,
+            },
+        );
+    }
+
     // Generated code spec.
     let spec_gen = if spec {
+        let sdk_specs = sdk_type_specs(
+            &ident.to_string(),
+            spec_fields.iter().map(|f| &f.type_),
+            &quote! { #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")] },
+        );
         let spec_entry = ScSpecEntry::UdtStructV0(ScSpecUdtStructV0 {
             name: ident.to_string().try_into().unwrap(),
             fields: spec_fields.try_into().unwrap(),
@@ -93,15 +119,62 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
         Some(quote! {
             #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")]
             pub static #spec_ident: [u8; #spec_xdr_len] = *#spec_xdr_lit;
+            #sdk_specs
         })
     } else {
         None
     };
 
+    // Generated code for versioned types, that stores the version alongside
+    // the fields and checks it when converting back.
+    let (version_gen, version_try_from, version_into, version_try_from_xdr, version_into_xdr) =
+        if let Some(version) = version {
+            let version_key = quote! {
+                { const k: soroban_sdk::Symbol = soroban_sdk::Symbol::from_str("_version"); k }
+            };
+            (
+                Some(quote! {
+                    impl soroban_sdk::Versioned for #ident {
+                        const VERSION: u32 = #version;
+                    }
+                }),
+                Some(quote! {
+                    if soroban_sdk::stored_version(&map)? != #version {
+                        Err(soroban_sdk::ConversionError)?
+                    }
+                }),
+                Some(quote! {
+                    map.set(#version_key, soroban_sdk::EnvVal { env: env.clone(), val: soroban_sdk::IntoVal::<soroban_sdk::Env, soroban_sdk::RawVal>::into_val(#version, env) });
+                }),
+                Some(quote! {
+                    let stored_version = match map.binary_search_by_key(&"_version".try_into().map_err(|_| soroban_sdk::xdr::Error::Invalid)?, |entry| entry.key.clone()) {
+                        Ok(idx) => match map[idx].val {
+                            soroban_sdk::xdr::ScVal::U32(v) => v,
+                            _ => Err(soroban_sdk::xdr::Error::Invalid)?,
+                        },
+                        Err(_) => 1,
+                    };
+                    if stored_version != #version {
+                        Err(soroban_sdk::xdr::Error::Invalid)?
+                    }
+                }),
+                Some(quote! {
+                    soroban_sdk::xdr::ScMapEntry {
+                        key: "_version".try_into().map_err(|_| soroban_sdk::xdr::Error::Invalid)?,
+                        val: soroban_sdk::xdr::ScVal::U32(#version),
+                    },
+                }),
+            )
+        } else {
+            (None, None, None, None, None)
+        };
+
     // Output.
     quote! {
         #spec_gen
 
+        #version_gen
+
         impl TryFrom<soroban_sdk::EnvVal> for #ident {
             type Error = soroban_sdk::ConversionError;
             #[inline(always)]
@@ -109,6 +182,7 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
                 use soroban_sdk::TryIntoVal;
                 let map: soroban_sdk::Map<soroban_sdk::Symbol, soroban_sdk::EnvVal> = ev.try_into()?;
                 let env = map.env();
+                #version_try_from
                 Ok(Self{
                     #(#try_froms,)*
                 })
@@ -128,6 +202,7 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
             fn into_val(self, env: &soroban_sdk::Env) -> soroban_sdk::RawVal {
                 let mut map = soroban_sdk::Map::<soroban_sdk::Symbol, soroban_sdk::EnvVal>::new(env);
                 #(#intos;)*
+                #version_into
                 map.into()
             }
         }
@@ -142,6 +217,7 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
                 use soroban_sdk::TryIntoVal;
                 let map = ev.val;
                 map.validate()?;
+                #version_try_from_xdr
                 Ok(Self{
                     #(#try_from_xdrs,)*
                 })
@@ -208,6 +284,7 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
             fn try_into(self) -> Result<soroban_sdk::xdr::ScMap, Self::Error> {
                 extern crate alloc;
                 soroban_sdk::xdr::ScMap::sorted_from(alloc::vec![
+                    #version_into_xdr
                     #(#into_xdrs,)*
                 ])
             }
@@ -257,6 +334,19 @@ pub fn derive_type_struct(ident: &Ident, data: &DataStruct, spec: bool) -> Token
                 (&self).try_into()
             }
         }
+
+        #[cfg(feature = "testutils")]
+        impl soroban_sdk::testutils::arbitrary::ArbitraryVal for #ident {
+            #[allow(unused_variables)]
+            fn arbitrary_val(
+                env: &soroban_sdk::Env,
+                u: &mut soroban_sdk::testutils::arbitrary::Unstructured<'_>,
+            ) -> soroban_sdk::testutils::arbitrary::Result<Self> {
+                Ok(Self{
+                    #(#arbitraries,)*
+                })
+            }
+        }
     }
 }
 
@@ -265,9 +355,10 @@ pub fn derive_type_enum(enum_ident: &Ident, data: &DataEnum, spec: bool) -> Toke
     let mut errors = Vec::<Error>::new();
 
     let variants = &data.variants;
-    let (spec_cases, discriminant_consts, try_froms, intos, try_from_xdrs, into_xdrs): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = variants
+    let (spec_cases, discriminant_consts, try_froms, intos, try_from_xdrs, into_xdrs, arbitraries): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = variants
         .iter()
-        .map(|v| {
+        .enumerate()
+        .map(|(i, v)| {
             // TODO: Choose discriminant type based on repr type of enum.
             // TODO: Should we use variants explicit discriminant? Probably not.
             // Should have a separate derive for those types of enums that maps
@@ -291,20 +382,8 @@ pub fn derive_type_enum(enum_ident: &Ident, data: &DataEnum, spec: bool) -> Toke
                 #discriminant_const_sym
                 #discriminant_const_u64
             };
-            if let Some(f) = field {
-                let spec_case = ScSpecUdtUnionCaseV0 {
-                    name: name.try_into().unwrap_or_else(|_| {
-                        errors.push(Error::new(ident.span(), "union case name too long"));
-                        VecM::default()
-                    }),
-                    type_: Some(match map_type(&f.ty) {
-                        Ok(t) => t,
-                        Err(e) => {
-                            errors.push(e);
-                            ScSpecTypeDef::I32
-                        }
-                    }),
-                };
+            let spec_case = spec_union_case(v, &mut errors);
+            if field.is_some() {
                 let try_from = quote! { #discriminant_const_u64_ident => Self::#ident(value.try_into_val(&env)?) };
                 let into = quote! { Self::#ident(value) => (#discriminant_const_sym_ident, value).into_val(env) };
                 let try_from_xdr = quote! {
@@ -318,20 +397,17 @@ pub fn derive_type_enum(enum_ident: &Ident, data: &DataEnum, spec: bool) -> Toke
                     )
                 };
                 let into_xdr = quote! { #enum_ident::#ident(value) => (#name, value).try_into().map_err(|_| soroban_sdk::xdr::Error::Invalid)? };
-                (spec_case, discriminant_const, try_from, into, try_from_xdr, into_xdr)
+                let i = i as u32;
+                let arbitrary = quote! { #i => Self::#ident(soroban_sdk::testutils::arbitrary::ArbitraryVal::arbitrary_val(env, u)?) };
+                (spec_case, discriminant_const, try_from, into, try_from_xdr, into_xdr, arbitrary)
             } else {
-                let spec_case = ScSpecUdtUnionCaseV0 {
-                    name: name.try_into().unwrap_or_else(|_| {
-                        errors.push(Error::new(ident.span(), "union case name too long"));
-                        VecM::default()
-                    }),
-                    type_: None,
-                };
                 let try_from = quote! { #discriminant_const_u64_ident => Self::#ident };
                 let into = quote! { Self::#ident => (#discriminant_const_sym_ident, ()).into_val(env) };
                 let try_from_xdr = quote! { #name => Self::#ident };
                 let into_xdr = quote! { #enum_ident::#ident => (#name, ()).try_into().map_err(|_| soroban_sdk::xdr::Error::Invalid)? };
-                (spec_case, discriminant_const, try_from, into, try_from_xdr, into_xdr)
+                let i = i as u32;
+                let arbitrary = quote! { #i => Self::#ident };
+                (spec_case, discriminant_const, try_from, into, try_from_xdr, into_xdr, arbitrary)
             }
         })
         .multiunzip();
@@ -344,22 +420,24 @@ pub fn derive_type_enum(enum_ident: &Ident, data: &DataEnum, spec: bool) -> Toke
 
     // Generated code spec.
     let spec_gen = if spec {
-        let spec_entry = ScSpecEntry::UdtUnionV0(ScSpecUdtUnionV0 {
-            name: enum_ident.to_string().try_into().unwrap(),
-            cases: spec_cases.try_into().unwrap(),
-        });
-        let spec_xdr = spec_entry.to_xdr().unwrap();
-        let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
-        let spec_xdr_len = spec_xdr.len();
-        let spec_ident = format_ident!("__SPEC_XDR_{}", enum_ident.to_string().to_uppercase());
-        Some(quote! {
-            #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")]
-            pub static #spec_ident: [u8; #spec_xdr_len] = *#spec_xdr_lit;
-        })
+        Some(spec_union(enum_ident, spec_cases, true))
     } else {
         None
     };
 
+    // Generated code arbitrary, choosing a variant and then generating its value.
+    let arbitrary_body = if let Some(max) = arbitraries.len().checked_sub(1) {
+        let max = max as u32;
+        quote! {
+            Ok(match u.int_in_range(0u32..=#max)? {
+                #(#arbitraries,)*
+                _ => unreachable!(),
+            })
+        }
+    } else {
+        quote! { Err(soroban_sdk::testutils::arbitrary::Error::IncorrectFormat) }
+    };
+
     // Output.
     quote! {
         #spec_gen
@@ -523,5 +601,88 @@ pub fn derive_type_enum(enum_ident: &Ident, data: &DataEnum, spec: bool) -> Toke
                 (&self).try_into()
             }
         }
+
+        #[cfg(feature = "testutils")]
+        impl soroban_sdk::testutils::arbitrary::ArbitraryVal for #enum_ident {
+            #[allow(unused_variables)]
+            fn arbitrary_val(
+                env: &soroban_sdk::Env,
+                u: &mut soroban_sdk::testutils::arbitrary::Unstructured<'_>,
+            ) -> soroban_sdk::testutils::arbitrary::Result<Self> {
+                #arbitrary_body
+            }
+        }
+    }
+}
+
+/// Generates only the spec of an enum, for types that implement their
+/// conversions by hand but are passed to contracts the same way a derived
+/// enum is. The spec is a const that is not placed in the contract spec
+/// section, so that it is only included in contracts that reference the type.
+pub fn derive_spec_enum(enum_ident: &Ident, data: &DataEnum) -> TokenStream2 {
+    let mut errors = Vec::<Error>::new();
+    let spec_cases: Vec<_> = data
+        .variants
+        .iter()
+        .map(|v| spec_union_case(v, &mut errors))
+        .collect();
+    if !errors.is_empty() {
+        let compile_errors = errors.iter().map(Error::to_compile_error);
+        return quote! { #(#compile_errors)* };
+    }
+    spec_union(enum_ident, spec_cases, false)
+}
+
+fn spec_union_case(v: &Variant, errors: &mut Vec<Error>) -> ScSpecUdtUnionCaseV0 {
+    let ident = &v.ident;
+    ScSpecUdtUnionCaseV0 {
+        name: ident.to_string().try_into().unwrap_or_else(|_| {
+            errors.push(Error::new(ident.span(), "union case name too long"));
+            VecM::default()
+        }),
+        type_: v.fields.iter().next().map(|f| match map_type(&f.ty) {
+            Ok(t) => t,
+            Err(e) => {
+                errors.push(e);
+                ScSpecTypeDef::I32
+            }
+        }),
+    }
+}
+
+fn spec_union(
+    enum_ident: &Ident,
+    spec_cases: Vec<ScSpecUdtUnionCaseV0>,
+    link: bool,
+) -> TokenStream2 {
+    let link_section =
+        quote! { #[cfg_attr(target_family = "wasm", link_section = "contractspecv0")] };
+    let sdk_specs = if link {
+        sdk_type_specs(
+            &enum_ident.to_string(),
+            spec_cases.iter().filter_map(|c| c.type_.as_ref()),
+            &link_section,
+        )
+    } else {
+        quote! {}
+    };
+    let spec_entry = ScSpecEntry::UdtUnionV0(ScSpecUdtUnionV0 {
+        name: enum_ident.to_string().try_into().unwrap(),
+        cases: spec_cases.try_into().unwrap(),
+    });
+    let spec_xdr = spec_entry.to_xdr().unwrap();
+    let spec_xdr_lit = proc_macro2::Literal::byte_string(spec_xdr.as_slice());
+    let spec_xdr_len = spec_xdr.len();
+    let spec_ident = format_ident!("__SPEC_XDR_{}", enum_ident.to_string().to_uppercase());
+    if link {
+        quote! {
+            #link_section
+            pub static #spec_ident: [u8; #spec_xdr_len] = *#spec_xdr_lit;
+            #sdk_specs
+        }
+    } else {
+        quote! {
+            pub const #spec_ident: [u8; #spec_xdr_len] = *#spec_xdr_lit;
+        }
     }
 }
@@ -1,18 +1,23 @@
 extern crate proc_macro;
 
 mod derive_fn;
+mod derive_storage;
 mod derive_type;
 mod map_type;
 
 use derive_fn::{derive_contract_function_set, derive_fn};
-use derive_type::{derive_type_enum, derive_type_struct};
+use derive_storage::derive_storage_enum;
+use derive_type::{derive_spec_enum, derive_type_enum, derive_type_struct};
 
 use darling::FromMeta;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-    parse_macro_input, spanned::Spanned, AttributeArgs, DeriveInput, Error, ImplItem,
-    ImplItemMethod, ItemImpl, Visibility,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    Attribute, AttributeArgs, DeriveInput, Error, Expr, ImplItem, ImplItemMethod, ItemImpl, LitInt,
+    LitStr, Token, Visibility,
 };
 
 #[derive(Debug, FromMeta)]
@@ -76,24 +81,187 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+#[derive(Debug, FromMeta)]
+struct ContractTypeArgs {
+    #[darling(default)]
+    version: Option<u32>,
+}
+
 #[proc_macro_attribute]
-pub fn contracttype(_metadata: TokenStream, input: TokenStream) -> TokenStream {
+pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(metadata as AttributeArgs);
+    let args = match ContractTypeArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
     let input = parse_macro_input!(input as DeriveInput);
+    let version = match (args.version, &input.data) {
+        (None, _) => None,
+        (Some(0), _) => {
+            return Error::new(input.ident.span(), "contract type versions start at 1")
+                .to_compile_error()
+                .into()
+        }
+        (Some(v), syn::Data::Struct(_)) => {
+            let v = proc_macro2::Literal::u32_unsuffixed(v);
+            Some(quote! { #[contractversion(#v)] })
+        }
+        (Some(_), _) => {
+            return Error::new(
+                input.ident.span(),
+                "versions are only supported on struct contract types",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
     quote! {
         #[derive(soroban_sdk::ContractType)]
+        #version
+        #input
+    }
+    .into()
+}
+
+#[proc_macro_attribute]
+pub fn contractstorage(_metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+    let vis = input.vis.clone();
+    let (derived, accessors) = match &mut input.data {
+        syn::Data::Enum(e) => {
+            let accessors = derive_storage_enum(&vis, &ident, e);
+            (derive_type_enum(&ident, e, false), accessors)
+        }
+        syn::Data::Struct(s) => (
+            Error::new(
+                s.struct_token.span(),
+                "structs are unsupported as contract storage keys",
+            )
+            .to_compile_error(),
+            quote! {},
+        ),
+        syn::Data::Union(u) => (
+            Error::new(
+                u.union_token.span(),
+                "unions are unsupported as contract storage keys",
+            )
+            .to_compile_error(),
+            quote! {},
+        ),
+    };
+    quote! {
         #input
+        #derived
+        #accessors
+    }
+    .into()
+}
+
+struct BytesNInput {
+    env: Expr,
+    lit: LitInt,
+}
+
+impl Parse for BytesNInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let env = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let lit = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(BytesNInput { env, lit })
+    }
+}
+
+/// Create a [BytesN](../soroban_sdk/struct.BytesN.html) from a hex integer
+/// literal, with a length of the number of bytes in the literal, checked at
+/// compile time.
+///
+/// Leading zeros in the literal are kept as zero bytes, so that `0x0001` is
+/// two bytes.
+#[proc_macro]
+pub fn bytesn(input: TokenStream) -> TokenStream {
+    let BytesNInput { env, lit } = parse_macro_input!(input as BytesNInput);
+    let digits: String = lit.to_string().chars().filter(|c| *c != '_').collect();
+    let hex = match digits.strip_prefix("0x") {
+        Some(hex) if !hex.is_empty() && hex.len() % 2 == 0 => hex,
+        Some(_) => {
+            return Error::new(lit.span(), "literal must have an even number of hex digits")
+                .to_compile_error()
+                .into()
+        }
+        None => {
+            return Error::new(lit.span(), "literal must be hex and begin with 0x")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let bytes: Result<Vec<u8>, _> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect();
+    let bytes = match bytes {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return Error::new(lit.span(), "literal must only contain hex digits")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let n = proc_macro2::Literal::usize_unsuffixed(bytes.len());
+    quote! {
+        soroban_sdk::BytesN::<#n>::from_array(#env, [#(#bytes),*])
+    }
+    .into()
+}
+
+/// Create a [Symbol](../soroban_sdk/struct.Symbol.html) from a string
+/// literal, checked at compile time to be no longer than 10 characters and to
+/// contain only the characters `[_0-9A-Za-z]`.
+#[proc_macro]
+pub fn symbol(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    let s = lit.value();
+    if s.len() > 10 {
+        return Error::new(lit.span(), "symbol must not be longer than 10 characters")
+            .to_compile_error()
+            .into();
+    }
+    if let Some(c) = s
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        return Error::new(
+            lit.span(),
+            format!(
+                "symbol must only contain characters [_0-9A-Za-z], found {:?}",
+                c
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+    quote! {
+        {
+            const SYMBOL: soroban_sdk::Symbol = soroban_sdk::Symbol::from_str(#lit);
+            SYMBOL
+        }
     }
     .into()
 }
 
 #[doc(hidden)]
-#[proc_macro_derive(ContractType)]
+#[proc_macro_derive(ContractType, attributes(contractversion))]
 pub fn derive_contract_type(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ident = &input.ident;
     let gen_spec = matches!(input.vis, Visibility::Public(_));
+    let version = match get_version(&input.attrs) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error().into(),
+    };
     let derived = match &input.data {
-        syn::Data::Struct(s) => derive_type_struct(ident, s, gen_spec),
+        syn::Data::Struct(s) => derive_type_struct(ident, s, gen_spec, version),
         syn::Data::Enum(e) => derive_type_enum(ident, e, gen_spec),
         syn::Data::Union(u) => Error::new(
             u.union_token.span(),
@@ -103,3 +271,24 @@ pub fn derive_contract_type(input: TokenStream) -> TokenStream {
     };
     quote! { #derived }.into()
 }
+
+/// Generates the spec of an enum without any conversions, for types in the
+/// SDK that implement their conversions by hand.
+#[doc(hidden)]
+#[proc_macro_derive(ContractSpec)]
+pub fn derive_contract_spec(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match &input.data {
+        syn::Data::Enum(e) => derive_spec_enum(&input.ident, e).into(),
+        _ => Error::new(input.ident.span(), "only enums are supported")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn get_version(attrs: &[Attribute]) -> Result<Option<u32>, Error> {
+    match attrs.iter().find(|a| a.path.is_ident("contractversion")) {
+        Some(a) => Ok(Some(a.parse_args::<LitInt>()?.base10_parse()?)),
+        None => Ok(None),
+    }
+}
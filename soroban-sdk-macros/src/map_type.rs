@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
 use stellar_xdr::{
     ScSpecTypeDef, ScSpecTypeMap, ScSpecTypeOption, ScSpecTypeSet, ScSpecTypeTuple, ScSpecTypeUdt,
     ScSpecTypeVec,
@@ -28,6 +30,10 @@ pub fn map_type(t: &Type) -> Result<ScSpecTypeDef, Error> {
                 "Status" => Ok(ScSpecTypeDef::Status),
                 "Bytes" => Ok(ScSpecTypeDef::Binary),
                 "BigInt" => Ok(ScSpecTypeDef::BigInt),
+                // The spec in stellar-xdr has no string type, so strings are
+                // described as the binary objects they are stored as.
+                // Contracts are no_std, and so a String is the SDK's String.
+                "String" => Ok(ScSpecTypeDef::Binary),
                 s => Ok(ScSpecTypeDef::Udt(ScSpecTypeUdt {
                     name: s.try_into().map_err(|e| {
                         Error::new(
@@ -120,3 +126,52 @@ pub fn map_type(t: &Type) -> Result<ScSpecTypeDef, Error> {
         _ => Err(Error::new(t.span(), "unsupported type"))?,
     }
 }
+
+// The contract types defined in the SDK, with their names in specs and the
+// consts in the SDK that hold their specs. The consts are not placed in the
+// contract spec section by the SDK, and are instead placed there by the items
+// of contracts that reference the types.
+const SDK_TYPE_SPECS: &[(&str, &str)] = &[("Identifier", "__SPEC_XDR_IDENTIFIER")];
+
+/// Generates statics that place the specs of the SDK's contract types that
+/// are referenced by the types into the contract spec section, so that only
+/// contracts that use an SDK type include its spec.
+pub fn sdk_type_specs<'a>(
+    item: &str,
+    types: impl IntoIterator<Item = &'a ScSpecTypeDef>,
+    link_section: &TokenStream2,
+) -> TokenStream2 {
+    let types: Vec<_> = types.into_iter().collect();
+    let statics = SDK_TYPE_SPECS
+        .iter()
+        .filter(|(name, _)| types.iter().any(|t| references_udt(t, name)))
+        .map(|(name, spec_const)| {
+            let spec_const = format_ident!("{}", spec_const);
+            let spec_ident = format_ident!(
+                "__SPEC_XDR_{}_{}",
+                item.to_uppercase(),
+                name.to_uppercase()
+            );
+            quote! {
+                #[doc(hidden)]
+                #link_section
+                pub static #spec_ident: [u8; soroban_sdk::#spec_const.len()] = soroban_sdk::#spec_const;
+            }
+        });
+    quote! { #(#statics)* }
+}
+
+// Returns true if the type is, or contains, the user-defined type.
+fn references_udt(t: &ScSpecTypeDef, name: &str) -> bool {
+    match t {
+        ScSpecTypeDef::Udt(udt) => udt.name.to_string().map_or(false, |n| n == name),
+        ScSpecTypeDef::Option(o) => references_udt(&o.value_type, name),
+        ScSpecTypeDef::Vec(v) => references_udt(&v.element_type, name),
+        ScSpecTypeDef::Set(s) => references_udt(&s.element_type, name),
+        ScSpecTypeDef::Map(m) => {
+            references_udt(&m.key_type, name) || references_udt(&m.value_type, name)
+        }
+        ScSpecTypeDef::Tuple(t) => t.value_types.iter().any(|t| references_udt(t, name)),
+        _ => false,
+    }
+}
@@ -1,11 +1,45 @@
-use core::{borrow::Borrow, cmp::Ordering, fmt::Debug};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
 
 use crate::{
     env::internal::{Env as _, RawVal, RawValConvertible},
     env::EnvObj,
-    Bytes, BytesN, ConversionError, Env, EnvType, EnvVal, IntoVal, Object,
+    strkey, Bytes, BytesN, ConversionError, Env, EnvType, EnvVal, IntoVal, Object, TryIntoVal, Vec,
 };
 
+/// Error returned when an [Account] cannot be created, or when signatures do
+/// not authorize an [Account].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AccountError {
+    /// The account does not exist in the ledger.
+    DoesNotExist,
+    /// The same signer signed more than once.
+    DuplicateSigner,
+    /// The combined weight of the signers does not meet the threshold.
+    ThresholdNotMet,
+}
+
+impl core::fmt::Display for AccountError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AccountError::DoesNotExist => write!(f, "account does not exist"),
+            AccountError::DuplicateSigner => write!(f, "duplicate signer"),
+            AccountError::ThresholdNotMet => write!(f, "threshold not met"),
+        }
+    }
+}
+
+/// Threshold selects one of the thresholds of a Stellar account.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Threshold {
+    Low,
+    Medium,
+    High,
+}
+
 /// Account references a Stellar account and provides access to information
 /// about the account, such as its thresholds and signers.
 #[derive(Clone)]
@@ -20,6 +54,12 @@ impl Debug for Account {
     }
 }
 
+impl Display for Account {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_strkey(), f)
+    }
+}
+
 impl Eq for Account {}
 
 impl PartialEq for Account {
@@ -124,6 +164,18 @@ impl TryFrom<EnvObj> for Account {
     }
 }
 
+impl TryIntoVal<Env, Account> for RawVal {
+    type Error = ConversionError;
+
+    fn try_into_val(self, env: &Env) -> Result<Account, Self::Error> {
+        EnvType {
+            env: env.clone(),
+            val: self,
+        }
+        .try_into()
+    }
+}
+
 impl From<Account> for RawVal {
     fn from(a: Account) -> Self {
         a.0.into()
@@ -165,14 +217,30 @@ impl Account {
 
     /// Creates an account from a public key.
     ///
-    /// TODO: Return a `Result` `Err` if the account does not exist. Currently panics if account does not exist.
-    pub fn from_public_key(public_key: &BytesN<32>) -> Result<Account, ()> {
-        let acc = Account(public_key.clone());
-        // TODO: Fail when account doesn't exist. In the meantime cause a trap
-        // at this point by trying to get some information about the account
-        // which will trap if the account doesn't exist.
-        _ = acc.low_threshold();
-        Ok(acc)
+    /// Returns [AccountError::DoesNotExist] if the account does not exist.
+    pub fn from_public_key(public_key: &BytesN<32>) -> Result<Account, AccountError> {
+        if Self::exists(public_key.env(), public_key) {
+            Ok(Account(public_key.clone()))
+        } else {
+            Err(AccountError::DoesNotExist)
+        }
+    }
+
+    /// Returns true if a Stellar account exists for the public key.
+    pub fn exists(env: &Env, public_key: &BytesN<32>) -> bool {
+        let val = env.account_exists(public_key.to_object());
+        val.is_true()
+    }
+
+    /// Creates an account from an account strkey (`G...`), without checking
+    /// that the account exists.
+    pub fn from_strkey(env: &Env, s: &str) -> Result<Account, strkey::Error> {
+        Ok(strkey::decode_account(s)?.into_val(env))
+    }
+
+    /// Returns the account strkey (`G...`) of the account.
+    pub fn to_strkey(&self) -> strkey::StrKey {
+        self.0.to_account_strkey()
     }
 
     /// Returns the low threshold for the Stellar account.
@@ -203,4 +271,58 @@ impl Account {
         let val = env.account_get_signer_weight(self.to_object(), signer.to_object());
         unsafe { <u32 as RawValConvertible>::unchecked_from_val(val) }
     }
+
+    /// Returns the threshold for the Stellar account.
+    pub fn threshold(&self, threshold: Threshold) -> u32 {
+        match threshold {
+            Threshold::Low => self.low_threshold(),
+            Threshold::Medium => self.medium_threshold(),
+            Threshold::High => self.high_threshold(),
+        }
+    }
+
+    /// Verifies that the ed25519 signatures of the message are from signers
+    /// of this Stellar account whose combined weight meets the threshold.
+    ///
+    /// Each signature is a pair of the public key of the signer and the
+    /// signature of the message. Signatures from keys that are not signers of
+    /// the account are verified but have no weight.
+    ///
+    /// A threshold of zero (`0`) is treated as one (`1`), the same as in
+    /// stellar-core, so that at least one signer of the account must sign even
+    /// when the account has the default thresholds.
+    ///
+    /// Returns [AccountError::DuplicateSigner] if a signer appears more than
+    /// once, and [AccountError::ThresholdNotMet] if the combined weight is
+    /// below the threshold, including when there are no signatures or when
+    /// none of the signers are signers of the account.
+    ///
+    /// ### Panics
+    ///
+    /// If any signature is not valid for the message.
+    pub fn verify_signatures(
+        &self,
+        env: &Env,
+        msg: &Bytes,
+        sigs: &Vec<(BytesN<32>, BytesN<64>)>,
+        threshold: Threshold,
+    ) -> Result<(), AccountError> {
+        let mut weight: u32 = 0;
+        for (i, (signer, sig)) in sigs.iter_unchecked().enumerate() {
+            let dup = sigs
+                .slice(..i as u32)
+                .iter_unchecked()
+                .any(|(prev, _)| prev == signer);
+            if dup {
+                return Err(AccountError::DuplicateSigner);
+            }
+            env.verify_sig_ed25519(signer.clone(), msg.clone(), sig);
+            weight = weight.saturating_add(self.signer_weight(&signer));
+        }
+        if weight >= self.threshold(threshold).max(1) {
+            Ok(())
+        } else {
+            Err(AccountError::ThresholdNotMet)
+        }
+    }
 }
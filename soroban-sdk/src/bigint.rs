@@ -8,7 +8,7 @@ use super::{
     env::internal::{Env as _, EnvBase, RawValConvertible},
     env::{EnvObj, EnvType},
     xdr::ScObjectType,
-    Bytes, ConversionError, Env, EnvVal, IntoVal, RawVal, TryFromVal, TryIntoVal,
+    Bytes, BytesN, ConversionError, Env, EnvVal, IntoVal, RawVal, TryFromVal, TryIntoVal,
 };
 
 /// BigInt is an arbitrary sized signed integer.
@@ -30,6 +30,14 @@ use super::{
 #[derive(Clone)]
 pub struct BigInt(EnvObj);
 
+/// Sign of a [BigInt].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Sign {
+    Minus,
+    NoSign,
+    Plus,
+}
+
 impl Debug for BigInt {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "BigInt(")?;
@@ -120,6 +128,22 @@ impl TryFrom<BigInt> for u64 {
     }
 }
 
+impl<const N: usize> TryFrom<BigInt> for BytesN<N> {
+    type Error = ConversionError;
+
+    fn try_from(b: BigInt) -> Result<Self, Self::Error> {
+        b.to_bytesn_be()
+    }
+}
+
+impl<const N: usize> TryFrom<&BigInt> for BytesN<N> {
+    type Error = ConversionError;
+
+    fn try_from(b: &BigInt) -> Result<Self, Self::Error> {
+        b.to_bytesn_be()
+    }
+}
+
 impl From<EnvType<u64>> for BigInt {
     fn from(ev: EnvType<u64>) -> Self {
         BigInt::from_u64(&ev.env, ev.val)
@@ -828,6 +852,159 @@ impl BigInt {
         i.try_into().unwrap()
     }
 
+    /// Creates a [BigInt] from the sign and the big-endian bytes of the
+    /// magnitude.
+    ///
+    /// A sign of [Sign::NoSign], or empty bytes, creates zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{BigInt, Bytes, Env, Sign};
+    ///
+    /// let env = Env::default();
+    /// let bytes = Bytes::from_array(&env, [0x01, 0x00]);
+    /// assert_eq!(BigInt::from_bytes_be(&env, Sign::Plus, &bytes), 256);
+    /// assert_eq!(BigInt::from_bytes_be(&env, Sign::Minus, &bytes), -256);
+    /// ```
+    pub fn from_bytes_be(env: &Env, sign: Sign, bytes: &Bytes) -> BigInt {
+        let sign: i32 = match sign {
+            Sign::Minus => -1,
+            Sign::NoSign => return BigInt::zero(env),
+            Sign::Plus => 1,
+        };
+        if bytes.is_empty() {
+            return BigInt::zero(env);
+        }
+        let b = env.bigint_from_bytes_be(sign.into(), bytes.to_object());
+        Self::try_from_val(env, b).unwrap()
+    }
+
+    /// Returns the sign and the big-endian bytes of the magnitude of the
+    /// [BigInt], with no leading zero bytes.
+    ///
+    /// Zero is returned as [Sign::NoSign] and empty bytes.
+    pub fn to_bytes_be(&self) -> (Sign, Bytes) {
+        let env = self.env();
+        let sign = self.sign();
+        if sign == Sign::NoSign {
+            return (sign, Bytes::new(env));
+        }
+        let obj: EnvObj = env.bigint_to_bytes_be(self.0.to_object()).in_env(env);
+        (sign, obj.try_into().unwrap())
+    }
+
+    /// Creates a [BigInt] from big-endian two's complement bytes.
+    ///
+    /// Empty bytes create zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{BigInt, Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let bytes = Bytes::from_array(&env, [0xff, 0x00]);
+    /// assert_eq!(BigInt::from_signed_bytes_be(&env, &bytes), -256);
+    /// ```
+    pub fn from_signed_bytes_be(env: &Env, bytes: &Bytes) -> BigInt {
+        let b = BigInt::from_bytes_be(env, Sign::Plus, bytes);
+        match bytes.first() {
+            Some(x) if x & 0x80 != 0 => b - (BigInt::from_u32(env, 1) << (bytes.len() as u64 * 8)),
+            _ => b,
+        }
+    }
+
+    /// Returns the [BigInt] as big-endian two's complement bytes, using the
+    /// fewest bytes that represent the value.
+    ///
+    /// Zero is returned as empty bytes.
+    pub fn to_signed_bytes_be(&self) -> Bytes {
+        let env = self.env();
+        let (sign, mag) = self.to_bytes_be();
+        match sign {
+            Sign::NoSign => mag,
+            Sign::Plus if mag.first_unchecked() & 0x80 == 0 => mag,
+            Sign::Plus => {
+                let mut bytes = Bytes::from_array(env, [0]);
+                bytes.append(&mag);
+                bytes
+            }
+            Sign::Minus => {
+                // Add 2^(8n) for the smallest n whose two's complement
+                // representation has the sign bit set.
+                let mut n = mag.len();
+                loop {
+                    let v = self.clone() + (BigInt::from_u32(env, 1) << (n as u64 * 8));
+                    let (_, bytes) = v.to_bytes_be();
+                    if bytes.len() == n && bytes.first_unchecked() & 0x80 != 0 {
+                        return bytes;
+                    }
+                    n += 1;
+                }
+            }
+        }
+    }
+
+    /// Returns the [BigInt] as big-endian bytes of length `N`, padded with
+    /// leading zeros.
+    ///
+    /// ### Errors
+    ///
+    /// When the [BigInt] is negative, or its magnitude does not fit in `N`
+    /// bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{BigInt, BytesN, Env};
+    ///
+    /// let env = Env::default();
+    /// let b = BigInt::from_u32(&env, 0x0102);
+    /// assert_eq!(b.to_bytesn_be::<4>(), Ok(BytesN::from_array(&env, [0, 0, 1, 2])));
+    /// assert!(b.to_bytesn_be::<1>().is_err());
+    /// ```
+    pub fn to_bytesn_be<const N: usize>(&self) -> Result<BytesN<N>, ConversionError> {
+        let (sign, mag) = self.to_bytes_be();
+        if sign == Sign::Minus {
+            return Err(ConversionError {});
+        }
+        pad_bytes_be(self.env(), 0, &mag)
+    }
+
+    /// Returns the [BigInt] as big-endian two's complement bytes of length
+    /// `N`, sign extended.
+    ///
+    /// ### Errors
+    ///
+    /// When the [BigInt] does not fit in `N` bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{BigInt, BytesN, Env};
+    ///
+    /// let env = Env::default();
+    /// let b = BigInt::from_i32(&env, -2);
+    /// assert_eq!(b.to_signed_bytesn_be::<2>(), Ok(BytesN::from_array(&env, [0xff, 0xfe])));
+    /// ```
+    pub fn to_signed_bytesn_be<const N: usize>(&self) -> Result<BytesN<N>, ConversionError> {
+        let bytes = self.to_signed_bytes_be();
+        let pad = if self.sign() == Sign::Minus { 0xff } else { 0 };
+        pad_bytes_be(self.env(), pad, &bytes)
+    }
+
+    /// Returns the [Sign] of the [BigInt].
+    pub fn sign(&self) -> Sign {
+        let env = self.env();
+        let v = env.bigint_cmp(self.0.to_object(), env.bigint_from_u64(0));
+        match i32::try_from(v).unwrap() {
+            i if i < 0 => Sign::Minus,
+            0 => Sign::NoSign,
+            _ => Sign::Plus,
+        }
+    }
+
     /// Returns the greatest common divisor of the [BigInt] and other.
     pub fn gcd(&self, other: BigInt) -> BigInt {
         let env = self.env();
@@ -882,6 +1059,21 @@ impl BigInt {
     }
 }
 
+/// Left pads the bytes with the pad byte to a length of `N`.
+fn pad_bytes_be<const N: usize>(
+    env: &Env,
+    pad: u8,
+    bytes: &Bytes,
+) -> Result<BytesN<N>, ConversionError> {
+    let len = bytes.len();
+    if len > N as u32 {
+        return Err(ConversionError {});
+    }
+    let mut padded = Bytes::from_array(env, [pad; N]).slice(..N as u32 - len);
+    padded.append(bytes);
+    padded.try_into()
+}
+
 #[test]
 fn test_bigint() {
     let env = Env::default();
@@ -892,3 +1084,101 @@ fn test_bigint() {
     let bi2 = BigInt::from_i64(&env, 0);
     println!("{:?}; {}", bi2, bi2);
 }
+
+#[test]
+fn test_bytes_be() {
+    let env = Env::default();
+
+    let bytes = Bytes::from_array(&env, [0x01, 0x02, 0x03]);
+    let b = BigInt::from_bytes_be(&env, Sign::Plus, &bytes);
+    assert_eq!(b, 0x010203);
+    assert_eq!(b.to_bytes_be(), (Sign::Plus, bytes.clone()));
+
+    let b = BigInt::from_bytes_be(&env, Sign::Minus, &bytes);
+    assert_eq!(b, -0x010203);
+    assert_eq!(b.to_bytes_be(), (Sign::Minus, bytes.clone()));
+
+    let b = BigInt::from_bytes_be(&env, Sign::NoSign, &bytes);
+    assert!(b.is_zero());
+    assert_eq!(b.to_bytes_be(), (Sign::NoSign, Bytes::new(&env)));
+
+    let b = BigInt::from_bytes_be(&env, Sign::Plus, &Bytes::new(&env));
+    assert!(b.is_zero());
+
+    let bytes = Bytes::from_array(&env, [0x00, 0x00, 0xff]);
+    let b = BigInt::from_bytes_be(&env, Sign::Plus, &bytes);
+    assert_eq!(b, 0xff);
+    assert_eq!(
+        b.to_bytes_be(),
+        (Sign::Plus, Bytes::from_array(&env, [0xff]))
+    );
+}
+
+#[test]
+fn test_signed_bytes_be() {
+    let env = Env::default();
+
+    let cases: [(i64, &[u8]); 10] = [
+        (0, &[]),
+        (1, &[0x01]),
+        (127, &[0x7f]),
+        (128, &[0x00, 0x80]),
+        (256, &[0x01, 0x00]),
+        (-1, &[0xff]),
+        (-128, &[0x80]),
+        (-129, &[0xff, 0x7f]),
+        (-256, &[0xff, 0x00]),
+        (i64::MIN, &[0x80, 0, 0, 0, 0, 0, 0, 0]),
+    ];
+    for (i, bytes) in cases {
+        let bytes = Bytes::from_slice(&env, bytes);
+        let b = BigInt::from_i64(&env, i);
+        assert_eq!(b.to_signed_bytes_be(), bytes);
+        assert_eq!(BigInt::from_signed_bytes_be(&env, &bytes), b);
+    }
+
+    let bytes = Bytes::from_array(&env, [0xff, 0xff, 0x7f]);
+    assert_eq!(BigInt::from_signed_bytes_be(&env, &bytes), -129);
+}
+
+#[test]
+fn test_bytesn_be() {
+    let env = Env::default();
+
+    let b = BigInt::from_u64(&env, 0x0102);
+    assert_eq!(b.to_bytesn_be(), Ok(BytesN::from_array(&env, [0, 0, 1, 2])));
+    assert_eq!(b.to_bytesn_be(), Ok(BytesN::from_array(&env, [1, 2])));
+    assert_eq!(b.to_bytesn_be::<1>(), Err(ConversionError {}));
+    assert_eq!(
+        BytesN::<2>::try_from(&b),
+        Ok(BytesN::from_array(&env, [1, 2]))
+    );
+    assert_eq!(
+        BytesN::<2>::try_from(b),
+        Ok(BytesN::from_array(&env, [1, 2]))
+    );
+
+    let b = BigInt::zero(&env);
+    assert_eq!(b.to_bytesn_be(), Ok(BytesN::from_array(&env, [0, 0])));
+    assert_eq!(b.to_bytesn_be(), Ok(BytesN::from_array(&env, [])));
+
+    let b = BigInt::from_i64(&env, -1);
+    assert_eq!(b.to_bytesn_be::<8>(), Err(ConversionError {}));
+    assert_eq!(
+        b.to_signed_bytesn_be(),
+        Ok(BytesN::from_array(&env, [0xff, 0xff, 0xff]))
+    );
+
+    let b = BigInt::from_i64(&env, 128);
+    assert_eq!(
+        b.to_signed_bytesn_be(),
+        Ok(BytesN::from_array(&env, [0, 0x80]))
+    );
+    assert_eq!(b.to_signed_bytesn_be::<1>(), Err(ConversionError {}));
+
+    let b = BigInt::from_u64(&env, u64::MAX) << 64u64;
+    let bytes: BytesN<16> = b.to_bytesn_be().unwrap();
+    assert_eq!(bytes.get_unchecked(0), 0xff);
+    assert_eq!(bytes.get_unchecked(15), 0);
+    assert_eq!(b.to_bytesn_be::<15>(), Err(ConversionError {}));
+}
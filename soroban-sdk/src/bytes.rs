@@ -1,20 +1,22 @@
 use core::{
     borrow::Borrow,
     cmp::Ordering,
-    fmt::Debug,
+    fmt::{Alignment, Debug, Display, LowerHex, Write},
     iter::FusedIterator,
     ops::{Bound, RangeBounds},
 };
 
 use super::{
-    env::internal::{Env as _, RawValConvertible},
+    env::internal::{Env as _, EnvBase as _, RawValConvertible},
     env::{EnvObj, EnvType, IntoVal},
+    iter::FromIteratorIn,
+    strkey,
     xdr::ScObjectType,
-    ConversionError, Env, EnvVal, Object, RawVal, TryIntoVal,
+    ConversionError, Env, EnvVal, Object, RawVal, TryIntoVal, Vec,
 };
 
 #[cfg(doc)]
-use crate::{ContractData, Map, Vec};
+use crate::{ContractData, Map};
 
 #[cfg(not(target_family = "wasm"))]
 use super::xdr::ScVal;
@@ -253,9 +255,16 @@ impl Bytes {
 
     #[inline(always)]
     pub fn from_slice(env: &Env, items: &[u8]) -> Bytes {
-        let mut vec = Bytes::new(env);
-        vec.extend_from_slice(items);
-        vec
+        let bin = env.binary_new_from_slice(items);
+        unsafe { Self::unchecked_new(bin.in_env(env)) }
+    }
+
+    /// Create a bytes containing the bytes of the iterator.
+    #[inline(always)]
+    pub fn from_iter<I: IntoIterator<Item = u8>>(env: &Env, iter: I) -> Bytes {
+        let mut bin = Bytes::new(env);
+        bin.extend(iter);
+        bin
     }
 
     #[inline(always)]
@@ -396,16 +405,42 @@ impl Bytes {
 
     #[inline(always)]
     pub fn extend_from_array<const N: usize>(&mut self, items: [u8; N]) {
-        for item in items {
-            self.push(item);
-        }
+        self.extend_from_slice(&items);
     }
 
     #[inline(always)]
     pub fn extend_from_slice(&mut self, items: &[u8]) {
-        for item in items {
-            self.push(*item);
-        }
+        self.copy_from_slice(self.len(), items);
+    }
+
+    /// Copy the bytes into the slice, starting with the first byte and
+    /// filling the slice.
+    ///
+    /// The bytes are copied in a single call to the host.
+    ///
+    /// ### Panics
+    ///
+    /// If the slice is longer than the bytes.
+    #[inline(always)]
+    pub fn copy_into_slice(&self, slice: &mut [u8]) {
+        let env = self.env();
+        env.binary_copy_to_slice(self.to_object(), 0u32.into(), slice);
+    }
+
+    /// Copy the slice into the bytes, starting at the index and overwriting
+    /// the bytes at and after the index. The bytes grow if the slice extends
+    /// past the end of the bytes.
+    ///
+    /// The slice is copied in a single call to the host.
+    ///
+    /// ### Panics
+    ///
+    /// If the index is greater than the length of the bytes.
+    #[inline(always)]
+    pub fn copy_from_slice(&mut self, i: u32, slice: &[u8]) {
+        let env = self.env();
+        let bin = env.binary_copy_from_slice(self.to_object(), i.into(), slice);
+        self.0 = bin.in_env(env);
     }
 
     #[must_use]
@@ -425,11 +460,367 @@ impl Bytes {
         unsafe { Self::unchecked_new(bin.in_env(env)) }
     }
 
+    /// Returns the bytes split into two at the index, the first containing
+    /// the bytes before the index and the second the bytes from the index.
+    ///
+    /// ### Panics
+    ///
+    /// If the index is greater than the length of the bytes.
+    #[must_use]
+    pub fn split_at(&self, i: u32) -> (Bytes, Bytes) {
+        if i > self.len() {
+            panic!("index out of bounds");
+        }
+        (self.slice(..i), self.slice(i..))
+    }
+
+    /// Returns an iterator over the subsequences of the bytes that are
+    /// separated by the separator byte. The separator is not included in
+    /// the subsequences.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{bin, Env};
+    ///
+    /// let env = Env::default();
+    /// let bin = bin![&env, 1, 0, 2, 3, 0];
+    /// let mut split = bin.split(0);
+    /// assert_eq!(split.next(), Some(bin![&env, 1]));
+    /// assert_eq!(split.next(), Some(bin![&env, 2, 3]));
+    /// assert_eq!(split.next(), Some(bin![&env]));
+    /// assert_eq!(split.next(), None);
+    /// ```
+    pub fn split(&self, sep: u8) -> BinSplit {
+        BinSplit {
+            rest: Some(self.clone()),
+            sep,
+        }
+    }
+
+    /// Returns true if the bytes begin with the prefix.
+    pub fn starts_with(&self, prefix: &Bytes) -> bool {
+        let n = prefix.len();
+        n <= self.len() && &self.slice(..n) == prefix
+    }
+
+    /// Returns true if the bytes end with the suffix.
+    pub fn ends_with(&self, suffix: &Bytes) -> bool {
+        let len = self.len();
+        let n = suffix.len();
+        n <= len && &self.slice(len - n..) == suffix
+    }
+
+    /// Returns the index of the first occurrence of the needle in the bytes,
+    /// or [None] if the needle does not occur in the bytes.
+    ///
+    /// An empty needle occurs at index zero (`0`).
+    pub fn find(&self, needle: &Bytes) -> Option<u32> {
+        let len = self.len();
+        let n = needle.len();
+        let first = match needle.first() {
+            Some(first) => first,
+            None => return Some(0),
+        };
+        if n > len {
+            return None;
+        }
+        (0..=len - n).find(|i| self.get_unchecked(*i) == first && &self.slice(*i..*i + n) == needle)
+    }
+
+    /// Returns the index of the first occurrence of the byte in the bytes,
+    /// or [None] if the byte does not occur in the bytes.
+    pub fn position(&self, x: u8) -> Option<u32> {
+        (0..self.len()).find(|i| self.get_unchecked(*i) == x)
+    }
+
+    /// Copy the bytes at the index into the array, returning [None] if the
+    /// bytes do not have enough bytes at the index to fill the array.
+    fn read_array<const N: usize>(&self, i: u32) -> Option<[u8; N]> {
+        let end = i.checked_add(N as u32)?;
+        if end > self.len() {
+            return None;
+        }
+        let mut array = [0u8; N];
+        let env = self.env();
+        env.binary_copy_to_slice(self.to_object(), i.into(), &mut array);
+        Some(array)
+    }
+
+    /// Returns the bytes encoded as lowercase hex, two ASCII characters
+    /// for each byte.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{bin, Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let bin = bin![&env, 0xab, 0x01];
+    /// assert_eq!(bin.to_hex(), Bytes::from_slice(&env, b"ab01"));
+    /// assert_eq!(Bytes::from_hex(&env, "ab01"), Ok(bin));
+    /// ```
+    pub fn to_hex(&self) -> Bytes {
+        let mut out = Bytes::new(self.env());
+        let mut hex = [0u8; CHUNK_LEN * 2];
+        self.for_each_chunk(|chunk| {
+            for (i, b) in chunk.iter().enumerate() {
+                hex[i * 2] = HEX_ALPHABET[(b >> 4) as usize];
+                hex[i * 2 + 1] = HEX_ALPHABET[(b & 0xf) as usize];
+            }
+            out.extend_from_slice(&hex[..chunk.len() * 2]);
+            Ok::<(), ()>(())
+        })
+        .unwrap();
+        out
+    }
+
+    /// Create a bytes from a hex string, two hex digits for each byte. Upper
+    /// and lowercase digits are accepted.
+    ///
+    /// Returns an error if the string has an odd number of characters or a
+    /// character that is not a hex digit.
+    pub fn from_hex(env: &Env, s: &str) -> Result<Bytes, ConversionError> {
+        let s = s.as_bytes();
+        if s.len() % 2 != 0 {
+            return Err(ConversionError);
+        }
+        let mut out = Bytes::new(env);
+        let mut buf = [0u8; CHUNK_LEN];
+        for chunk in s.chunks(CHUNK_LEN * 2) {
+            for (i, pair) in chunk.chunks(2).enumerate() {
+                buf[i] = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+            }
+            out.extend_from_slice(&buf[..chunk.len() / 2]);
+        }
+        Ok(out)
+    }
+
+    /// Returns the bytes encoded as base64, using the standard alphabet with
+    /// padding.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let bin = Bytes::from_slice(&env, b"hello");
+    /// assert_eq!(bin.to_base64(), Bytes::from_slice(&env, b"aGVsbG8="));
+    /// assert_eq!(Bytes::from_base64(&env, "aGVsbG8="), Ok(bin));
+    /// ```
+    pub fn to_base64(&self) -> Bytes {
+        let mut out = Bytes::new(self.env());
+        let mut enc = [0u8; CHUNK_LEN / 3 * 4];
+        self.for_each_chunk(|chunk| {
+            let mut n = 0;
+            for group in chunk.chunks(3) {
+                let b = [
+                    group[0],
+                    *group.get(1).unwrap_or(&0),
+                    *group.get(2).unwrap_or(&0),
+                ];
+                enc[n] = BASE64_ALPHABET[(b[0] >> 2) as usize];
+                enc[n + 1] = BASE64_ALPHABET[(((b[0] & 0x3) << 4) | (b[1] >> 4)) as usize];
+                enc[n + 2] = if group.len() > 1 {
+                    BASE64_ALPHABET[(((b[1] & 0xf) << 2) | (b[2] >> 6)) as usize]
+                } else {
+                    b'='
+                };
+                enc[n + 3] = if group.len() > 2 {
+                    BASE64_ALPHABET[(b[2] & 0x3f) as usize]
+                } else {
+                    b'='
+                };
+                n += 4;
+            }
+            out.extend_from_slice(&enc[..n]);
+            Ok::<(), ()>(())
+        })
+        .unwrap();
+        out
+    }
+
+    /// Create a bytes from a base64 string, using the standard alphabet with
+    /// padding.
+    ///
+    /// Returns an error if the string is not a multiple of four characters,
+    /// contains a character outside of the alphabet, or is padded anywhere
+    /// other than at its end.
+    pub fn from_base64(env: &Env, s: &str) -> Result<Bytes, ConversionError> {
+        let s = s.as_bytes();
+        if s.len() % 4 != 0 {
+            return Err(ConversionError);
+        }
+        let mut out = Bytes::new(env);
+        let mut buf = [0u8; CHUNK_LEN];
+        let groups = s.len() / 4;
+        for (c, chunk) in s.chunks(CHUNK_LEN / 3 * 4).enumerate() {
+            let mut n = 0;
+            for (g, group) in chunk.chunks(4).enumerate() {
+                let last = c * (CHUNK_LEN / 3) + g + 1 == groups;
+                let pad = match (group[2], group[3]) {
+                    (b'=', b'=') if last => 2,
+                    (_, b'=') if last => 1,
+                    _ => 0,
+                };
+                let mut v: u32 = 0;
+                for x in &group[..4 - pad] {
+                    v = (v << 6) | base64_digit(*x)? as u32;
+                }
+                v <<= 6 * pad as u32;
+                let b = v.to_be_bytes();
+                buf[n..n + 3 - pad].copy_from_slice(&b[1..4 - pad]);
+                n += 3 - pad;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        Ok(out)
+    }
+
+    /// Calls the function with consecutive chunks of the bytes, each copied
+    /// out of the host in a single call.
+    pub(crate) fn for_each_chunk<E>(
+        &self,
+        mut f: impl FnMut(&[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let env = self.env();
+        let len = self.len();
+        let mut buf = [0u8; CHUNK_LEN];
+        let mut i = 0;
+        while i < len {
+            let n = (len - i).min(CHUNK_LEN as u32);
+            let chunk = &mut buf[..n as usize];
+            env.binary_copy_to_slice(self.to_object(), i.into(), chunk);
+            f(chunk)?;
+            i += n;
+        }
+        Ok(())
+    }
+
     pub fn iter(&self) -> BinIter {
         self.clone().into_iter()
     }
 }
 
+// The length of the chunks that bytes are copied out of the host in when
+// encoding and formatting. A multiple of three so that base64 encodes whole
+// chunks without padding.
+pub(crate) const CHUNK_LEN: usize = 48;
+
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn hex_digit(c: u8) -> Result<u8, ConversionError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ConversionError),
+    }
+}
+
+fn base64_digit(c: u8) -> Result<u8, ConversionError> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(ConversionError),
+    }
+}
+
+macro_rules! impl_bytes_int {
+    ($t:ty, $read_be:ident, $read_le:ident, $push_be:ident, $push_le:ident) => {
+        impl Bytes {
+            #[doc = concat!("Returns the `", stringify!($t), "` encoded big-endian at the index, or [None] if the bytes are too short.")]
+            #[inline(always)]
+            pub fn $read_be(&self, i: u32) -> Option<$t> {
+                self.read_array(i).map(<$t>::from_be_bytes)
+            }
+
+            #[doc = concat!("Returns the `", stringify!($t), "` encoded little-endian at the index, or [None] if the bytes are too short.")]
+            #[inline(always)]
+            pub fn $read_le(&self, i: u32) -> Option<$t> {
+                self.read_array(i).map(<$t>::from_le_bytes)
+            }
+
+            #[doc = concat!("Appends the `", stringify!($t), "` encoded big-endian to the end of the bytes.")]
+            #[inline(always)]
+            pub fn $push_be(&mut self, x: $t) {
+                self.extend_from_array(x.to_be_bytes());
+            }
+
+            #[doc = concat!("Appends the `", stringify!($t), "` encoded little-endian to the end of the bytes.")]
+            #[inline(always)]
+            pub fn $push_le(&mut self, x: $t) {
+                self.extend_from_array(x.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_bytes_int!(u16, read_u16_be, read_u16_le, push_u16_be, push_u16_le);
+impl_bytes_int!(u32, read_u32_be, read_u32_le, push_u32_be, push_u32_le);
+impl_bytes_int!(u64, read_u64_be, read_u64_le, push_u64_be, push_u64_le);
+impl_bytes_int!(u128, read_u128_be, read_u128_le, push_u128_be, push_u128_le);
+impl_bytes_int!(i32, read_i32_be, read_i32_le, push_i32_be, push_i32_le);
+impl_bytes_int!(i64, read_i64_be, read_i64_le, push_i64_be, push_i64_le);
+
+impl Vec<Bytes> {
+    /// Returns the bytes of the vec joined together in order.
+    #[inline(always)]
+    pub fn concat(&self) -> Bytes {
+        let mut concatenated = Bytes::new(self.env());
+        for bin in self.iter_unchecked() {
+            concatenated.append(&bin);
+        }
+        concatenated
+    }
+}
+
+/// BinSplit is an iterator over the subsequences of a [Bytes] separated by a
+/// byte, returned by [Bytes::split].
+#[derive(Clone)]
+pub struct BinSplit {
+    rest: Option<Bytes>,
+    sep: u8,
+}
+
+impl Iterator for BinSplit {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        match rest.position(self.sep) {
+            Some(i) => {
+                self.rest = Some(rest.slice(i + 1..));
+                Some(rest.slice(..i))
+            }
+            None => Some(rest),
+        }
+    }
+}
+
+impl FusedIterator for BinSplit {}
+
+impl Extend<u8> for Bytes {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl FromIteratorIn<u8> for Bytes {
+    fn from_iter_in<I: IntoIterator<Item = u8>>(env: &Env, iter: I) -> Self {
+        Bytes::from_iter(env, iter)
+    }
+}
+
 impl IntoIterator for Bytes {
     type Item = u8;
     type IntoIter = BinIter;
@@ -530,6 +921,39 @@ impl<const N: usize> Debug for BytesN<N> {
     }
 }
 
+impl<const N: usize> Display for BytesN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0
+            .for_each_chunk(|chunk| chunk.iter().try_for_each(|b| write!(f, "{:02x}", b)))
+    }
+}
+
+/// Formats the bytes as lowercase hex, prefixed with `0x` when the alternate
+/// flag is set. Width, fill, alignment and the `0` flag are applied the same
+/// way as for integers, without copying the hex into a single buffer.
+impl<const N: usize> LowerHex for BytesN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let len = prefix.len() + N * 2;
+        let pad = f.width().map_or(0, |w| w.saturating_sub(len));
+        if f.sign_aware_zero_pad() {
+            f.write_str(prefix)?;
+            (0..pad).try_for_each(|_| f.write_char('0'))?;
+            return Display::fmt(self, f);
+        }
+        let (pre, post) = match f.align() {
+            Some(Alignment::Left) => (0, pad),
+            Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+            Some(Alignment::Right) | None => (pad, 0),
+        };
+        let fill = f.fill();
+        (0..pre).try_for_each(|_| f.write_char(fill))?;
+        f.write_str(prefix)?;
+        Display::fmt(self, f)?;
+        (0..post).try_for_each(|_| f.write_char(fill))
+    }
+}
+
 impl<const N: usize> Eq for BytesN<N> {}
 
 impl<const N: usize> PartialEq for BytesN<N> {
@@ -725,9 +1149,26 @@ impl<const N: usize> BytesN<N> {
 
     #[inline(always)]
     pub fn from_array(env: &Env, items: [u8; N]) -> BytesN<N> {
-        let mut bin = Bytes::new(env);
-        bin.extend_from_array(items);
-        BytesN(bin)
+        BytesN(Bytes::from_slice(env, &items))
+    }
+
+    /// Copy the bytes into the slice, starting with the first byte and
+    /// filling the slice.
+    ///
+    /// ### Panics
+    ///
+    /// If the slice is longer than `N`.
+    #[inline(always)]
+    pub fn copy_into_slice(&self, slice: &mut [u8]) {
+        self.0.copy_into_slice(slice);
+    }
+
+    /// Returns the bytes as an array, copied in a single call to the host.
+    #[inline(always)]
+    pub fn to_array(&self) -> [u8; N] {
+        let mut array = [0u8; N];
+        self.copy_into_slice(&mut array);
+        array
     }
 
     #[inline(always)]
@@ -780,6 +1221,30 @@ impl<const N: usize> BytesN<N> {
     }
 }
 
+impl BytesN<32> {
+    /// Creates a [BytesN] from the public key encoded in an account strkey
+    /// (`G...`).
+    pub fn from_account_strkey(env: &Env, s: &str) -> Result<BytesN<32>, strkey::Error> {
+        Ok(BytesN::from_array(env, strkey::decode_account(s)?))
+    }
+
+    /// Creates a [BytesN] from the contract id encoded in a contract strkey
+    /// (`C...`).
+    pub fn from_contract_strkey(env: &Env, s: &str) -> Result<BytesN<32>, strkey::Error> {
+        Ok(BytesN::from_array(env, strkey::decode_contract(s)?))
+    }
+
+    /// Encodes the bytes as an account strkey (`G...`).
+    pub fn to_account_strkey(&self) -> strkey::StrKey {
+        strkey::encode_account(&self.to_array())
+    }
+
+    /// Encodes the bytes as a contract strkey (`C...`).
+    pub fn to_contract_strkey(&self) -> strkey::StrKey {
+        strkey::encode_contract(&self.to_array())
+    }
+}
+
 impl<const N: usize> IntoIterator for BytesN<N> {
     type Item = u8;
 
@@ -801,11 +1266,7 @@ impl<const N: usize> TryFrom<Bytes> for [u8; N] {
 
 impl<const N: usize> From<BytesN<N>> for [u8; N] {
     fn from(bin: BytesN<N>) -> Self {
-        let mut res = [0u8; N];
-        for (i, b) in bin.into_iter().enumerate() {
-            res[i] = b;
-        }
-        res
+        bin.to_array()
     }
 }
 
@@ -813,6 +1274,190 @@ impl<const N: usize> From<BytesN<N>> for [u8; N] {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_hex() {
+        let env = Env::default();
+
+        let bin = Bytes::from_slice(&env, &[0x00, 0x1f, 0xa0, 0xff]);
+        assert_eq!(bin.to_hex(), Bytes::from_slice(&env, b"001fa0ff"));
+        assert_eq!(Bytes::from_hex(&env, "001fa0ff"), Ok(bin.clone()));
+        assert_eq!(Bytes::from_hex(&env, "001FA0FF"), Ok(bin));
+        assert_eq!(Bytes::new(&env).to_hex(), Bytes::new(&env));
+        assert_eq!(Bytes::from_hex(&env, ""), Ok(Bytes::new(&env)));
+        assert!(Bytes::from_hex(&env, "001").is_err());
+        assert!(Bytes::from_hex(&env, "0g").is_err());
+
+        // Longer than a single chunk.
+        let bin = Bytes::from_iter(&env, 0..=255);
+        assert_eq!(bin.to_hex().len(), 512);
+        assert_eq!(
+            bin.to_hex().slice(504..),
+            Bytes::from_slice(&env, b"fcfdfeff")
+        );
+        let hex = std::string::String::from_utf8(bin.to_hex().iter().collect()).unwrap();
+        assert_eq!(Bytes::from_hex(&env, &hex), Ok(bin));
+    }
+
+    #[test]
+    fn test_base64() {
+        let env = Env::default();
+
+        let cases: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+        for (raw, enc) in cases {
+            let bin = Bytes::from_slice(&env, raw);
+            assert_eq!(bin.to_base64(), Bytes::from_slice(&env, enc.as_bytes()));
+            assert_eq!(Bytes::from_base64(&env, enc), Ok(bin));
+        }
+        assert!(Bytes::from_base64(&env, "Zg=").is_err());
+        assert!(Bytes::from_base64(&env, "Zg==Zg==").is_err());
+        assert!(Bytes::from_base64(&env, "Z!==").is_err());
+
+        // Longer than a single chunk.
+        let bin = Bytes::from_iter(&env, 0..=255);
+        let enc = std::string::String::from_utf8(bin.to_base64().iter().collect()).unwrap();
+        assert_eq!(enc.len(), 344);
+        assert_eq!(Bytes::from_base64(&env, &enc), Ok(bin));
+    }
+
+    #[test]
+    fn test_bytesn_fmt() {
+        let env = Env::default();
+
+        let bin = BytesN::from_array(&env, [0x00, 0xab, 0x10]);
+        assert_eq!(format!("{}", bin), "00ab10");
+        assert_eq!(format!("{:x}", bin), "00ab10");
+        assert_eq!(format!("{:#x}", bin), "0x00ab10");
+        assert_eq!(format!("{:10x}", bin), "    00ab10");
+        assert_eq!(format!("{:*<#10x}", bin), "0x00ab10**");
+        assert_eq!(format!("{:^11x}", bin), "  00ab10   ");
+        assert_eq!(format!("{:#012x}", bin), "0x000000ab10");
+        assert_eq!(format!("{:4x}", bin), "00ab10");
+    }
+
+    #[test]
+    fn test_bin_search() {
+        let env = Env::default();
+
+        let bin = bin![&env, 1, 2, 3, 1, 2, 4];
+        assert!(bin.starts_with(&bin![&env, 1, 2]));
+        assert!(bin.starts_with(&bin![&env]));
+        assert!(!bin.starts_with(&bin![&env, 2]));
+        assert!(bin.ends_with(&bin![&env, 2, 4]));
+        assert!(!bin.ends_with(&bin![&env, 1, 2, 3, 1, 2, 4, 5]));
+        assert_eq!(bin.find(&bin![&env, 1, 2]), Some(0));
+        assert_eq!(bin.find(&bin![&env, 1, 2, 4]), Some(3));
+        assert_eq!(bin.find(&bin![&env, 2, 1]), None);
+        assert_eq!(bin.find(&bin![&env]), Some(0));
+        assert_eq!(bin.position(3), Some(2));
+        assert_eq!(bin.position(5), None);
+    }
+
+    #[test]
+    fn test_bin_split() {
+        let env = Env::default();
+
+        let bin = bin![&env, 1, 2, 3];
+        assert_eq!(bin.split_at(1), (bin![&env, 1], bin![&env, 2, 3]));
+        assert_eq!(bin.split_at(3), (bin![&env, 1, 2, 3], bin![&env]));
+
+        let parts: std::vec::Vec<Bytes> = bin![&env, 0, 1, 0, 0, 2].split(0).collect();
+        assert_eq!(
+            parts,
+            [bin![&env], bin![&env, 1], bin![&env], bin![&env, 2]]
+        );
+        let parts: std::vec::Vec<Bytes> = bin![&env].split(0).collect();
+        assert_eq!(parts, [bin![&env]]);
+
+        let vec = Vec::from_array(&env, [bin![&env, 1], bin![&env], bin![&env, 2, 3]]);
+        assert_eq!(vec.concat(), bin![&env, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_bin_split_at_out_of_bounds() {
+        let env = Env::default();
+        let _ = bin![&env, 1].split_at(2);
+    }
+
+    #[test]
+    fn test_bin_ints() {
+        let env = Env::default();
+
+        let mut bin = Bytes::new(&env);
+        bin.push_u16_be(0x0102);
+        bin.push_u32_le(0x03040506);
+        bin.push_u64_be(u64::MAX - 1);
+        bin.push_i32_be(-2);
+        assert_eq!(bin.len(), 18);
+        assert_eq!(bin.slice(..6), bin![&env, 1, 2, 6, 5, 4, 3]);
+        assert_eq!(bin.read_u16_be(0), Some(0x0102));
+        assert_eq!(bin.read_u16_le(0), Some(0x0201));
+        assert_eq!(bin.read_u32_le(2), Some(0x03040506));
+        assert_eq!(bin.read_u64_be(6), Some(u64::MAX - 1));
+        assert_eq!(bin.read_i32_be(14), Some(-2));
+        assert_eq!(bin.read_i32_be(15), None);
+        assert_eq!(bin.read_u128_le(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_bin_copy_slice() {
+        let env = Env::default();
+
+        let bin = Bytes::from_slice(&env, &[1, 2, 3, 4]);
+        assert_eq!(bin, bin![&env, 1, 2, 3, 4]);
+
+        let mut buf = [0u8; 3];
+        bin.copy_into_slice(&mut buf);
+        assert_eq!(buf, [1, 2, 3]);
+
+        let mut bin = bin;
+        bin.copy_from_slice(1, &[5, 6]);
+        assert_eq!(bin, bin![&env, 1, 5, 6, 4]);
+        bin.copy_from_slice(3, &[7, 8]);
+        assert_eq!(bin, bin![&env, 1, 5, 6, 7, 8]);
+        bin.extend_from_slice(&[9]);
+        assert_eq!(bin, bin![&env, 1, 5, 6, 7, 8, 9]);
+
+        let bin = BytesN::from_array(&env, [1, 2, 3]);
+        assert_eq!(bin.to_array(), [1, 2, 3]);
+        let array: [u8; 3] = bin.into();
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bin_copy_into_longer_slice() {
+        let env = Env::default();
+        let bin = Bytes::from_slice(&env, &[1, 2]);
+        let mut buf = [0u8; 3];
+        bin.copy_into_slice(&mut buf);
+    }
+
+    #[test]
+    fn test_bin_from_iter() {
+        use crate::iter::CollectIn;
+
+        let env = Env::default();
+
+        let bin = Bytes::from_iter(&env, 1..4);
+        assert_eq!(bin, bin![&env, 1, 2, 3]);
+
+        let bin: Bytes = bin.iter().rev().collect_in(&env);
+        assert_eq!(bin, bin![&env, 3, 2, 1]);
+
+        let mut bin = bin;
+        bin.extend([4, 5]);
+        assert_eq!(bin, bin![&env, 3, 2, 1, 4, 5]);
+    }
+
     #[test]
     fn test_bin_macro() {
         let env = Env::default();
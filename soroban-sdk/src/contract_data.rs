@@ -1,8 +1,8 @@
-use core::fmt::Debug;
+use core::{fmt::Debug, marker::PhantomData};
 
 use crate::{
     env::internal::{self, RawVal},
-    Env, IntoVal, TryFromVal,
+    ConversionError, Env, EnvVal, IntoVal, Map, Object, Symbol, TryFromVal,
 };
 
 /// ContractData stores and retrieves data for the currently executing contract.
@@ -85,9 +85,9 @@ impl ContractData {
     ///
     /// When the value stored cannot be converted into the type expected.
     ///
-    /// ### TODO
-    ///
-    /// Add safe checked versions of these functions.
+    /// See [ContractData::try_get] for a checked version that returns a
+    /// [Result] holding an [Option], and [ContractData::get_or] and
+    /// [ContractData::get_or_default] for values with a fallback.
     #[inline(always)]
     pub fn get<K, V>(&self, key: K) -> Option<Result<V, V::Error>>
     where
@@ -147,4 +147,976 @@ impl ContractData {
         let env = self.env();
         internal::Env::del_contract_data(env, key.into_val(env));
     }
+
+    /// Returns the value stored for the given key in the currently executing
+    /// contracts data, or [None] if the key does not have a value stored.
+    ///
+    /// Returns an error if the value stored cannot be converted into the type
+    /// expected.
+    #[inline(always)]
+    pub fn try_get<K, V>(&self, key: K) -> Result<Option<V>, V::Error>
+    where
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        let env = self.env();
+        let key = key.into_val(env);
+        let has = internal::Env::has_contract_data(env, key);
+        if has.is_true() {
+            let rv = internal::Env::get_contract_data(env, key);
+            Ok(Some(V::try_from_val(env, rv)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the value stored for the given key in the currently executing
+    /// contracts data, or the default if the key does not have a value
+    /// stored.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    #[inline(always)]
+    pub fn get_or<K, V>(&self, key: K, default: V) -> V
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        self.try_get(key).unwrap().unwrap_or(default)
+    }
+
+    /// Returns the value stored for the given key in the currently executing
+    /// contracts data, or the default value of the type if the key does not
+    /// have a value stored.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    #[inline(always)]
+    pub fn get_or_default<K, V>(&self, key: K) -> V
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal> + Default,
+    {
+        self.try_get(key).unwrap().unwrap_or_default()
+    }
+
+    /// Removes the value stored for the given key in the currently executing
+    /// contracts data, returning the value, or [None] if the key does not
+    /// have a value stored.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    #[inline(always)]
+    pub fn take<K, V>(&self, key: K) -> Option<V>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        let env = self.env();
+        let key = key.into_val(env);
+        let val = self.try_get(key).unwrap();
+        if val.is_some() {
+            internal::Env::del_contract_data(env, key);
+        }
+        val
+    }
+
+    /// Updates the value stored for the given key in the currently executing
+    /// contracts data with the value returned by the function, returning the
+    /// new value.
+    ///
+    /// The function is called with the value currently stored, or [None] if
+    /// the key does not have a value stored.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, Symbol};
+    ///
+    /// # use soroban_sdk::{contractimpl, BytesN};
+    /// #
+    /// # pub struct Contract;
+    /// #
+    /// # #[contractimpl]
+    /// # impl Contract {
+    /// #     pub fn f(env: Env) {
+    /// let contract_data = env.contract_data();
+    /// let key = Symbol::from_str("count");
+    /// contract_data.update(key, |v: Option<u32>| v.unwrap_or(0) + 1);
+    /// contract_data.update(key, |v: Option<u32>| v.unwrap_or(0) + 1);
+    /// assert_eq!(contract_data.get_or(key, 0u32), 2);
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// #     let env = Env::default();
+    /// #     let contract_id = BytesN::from_array(&env, [0; 32]);
+    /// #     env.register_contract(&contract_id, Contract);
+    /// #     f::invoke(&env, &contract_id);
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    #[inline(always)]
+    pub fn update<K, V, F>(&self, key: K, f: F) -> V
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+        F: FnOnce(Option<V>) -> V,
+    {
+        let env = self.env();
+        let key = key.into_val(env);
+        let val = f(self.try_get(key).unwrap());
+        internal::Env::put_contract_data(env, key, val.clone().into_val(env));
+        val
+    }
+
+    /// Returns the value stored for the given key in the currently executing
+    /// contracts data, migrating it from the previous version of the type if
+    /// it was stored with that version, or [None] if the key does not have a
+    /// value stored.
+    ///
+    /// If `rewrite` is true a migrated value is stored in place of the value
+    /// of the previous version, so that later reads do not need to migrate
+    /// it again.
+    ///
+    /// Returns an error if the value stored is neither the version of the
+    /// type expected nor its previous version, or cannot be converted into
+    /// the type of that version.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contracttype, Env, Migrate, Symbol};
+    ///
+    /// #[contracttype(version = 1)]
+    /// pub struct ConfigV1 {
+    ///     pub limit: u32,
+    /// }
+    ///
+    /// #[contracttype(version = 2)]
+    /// #[derive(Clone)]
+    /// pub struct Config {
+    ///     pub limit: u32,
+    ///     pub paused: bool,
+    /// }
+    ///
+    /// impl Migrate for Config {
+    ///     type Previous = ConfigV1;
+    ///
+    ///     fn migrate(_env: &Env, prev: ConfigV1) -> Self {
+    ///         Config { limit: prev.limit, paused: false }
+    ///     }
+    /// }
+    ///
+    /// # use soroban_sdk::{contractimpl, BytesN};
+    /// #
+    /// # pub struct Contract;
+    /// #
+    /// # #[contractimpl]
+    /// # impl Contract {
+    /// #     pub fn f(env: Env) {
+    /// let contract_data = env.contract_data();
+    /// let key = Symbol::from_str("config");
+    /// contract_data.set(key, ConfigV1 { limit: 5 });
+    ///
+    /// let config: Config = contract_data.get_migrating(key, true).unwrap().unwrap();
+    /// assert_eq!(config.limit, 5);
+    /// assert!(!config.paused);
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// #     let env = Env::default();
+    /// #     let contract_id = BytesN::from_array(&env, [0; 32]);
+    /// #     env.register_contract(&contract_id, Contract);
+    /// #     f::invoke(&env, &contract_id);
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn get_migrating<K, V>(&self, key: K, rewrite: bool) -> Result<Option<V>, ConversionError>
+    where
+        K: IntoVal<Env, RawVal>,
+        V: Migrate + IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    {
+        let env = self.env();
+        let key = key.into_val(env);
+        let has = internal::Env::has_contract_data(env, key);
+        if !has.is_true() {
+            return Ok(None);
+        }
+        let rv = internal::Env::get_contract_data(env, key);
+        let version = match Map::<Symbol, EnvVal>::try_from_val(env, rv) {
+            Ok(map) => stored_version(&map)?,
+            Err(_) => 1,
+        };
+        if version == V::VERSION {
+            V::try_from_val(env, rv)
+                .map(Some)
+                .map_err(|_| ConversionError)
+        } else if version == V::Previous::VERSION {
+            let prev = V::Previous::try_from_val(env, rv).map_err(|_| ConversionError)?;
+            let val = V::migrate(env, prev);
+            if rewrite {
+                internal::Env::put_contract_data(env, key, val.clone().into_val(env));
+            }
+            Ok(Some(val))
+        } else {
+            Err(ConversionError)
+        }
+    }
+
+    /// Returns a [CachedContractData] that caches reads and buffers writes
+    /// of the currently executing contracts data until it is committed or
+    /// dropped.
+    #[inline(always)]
+    pub fn cached(&self) -> CachedContractData {
+        CachedContractData::new(self.clone())
+    }
+
+    /// Returns a [CachedContractData] like [ContractData::cached] that holds
+    /// up to `N` keys.
+    #[inline(always)]
+    pub fn cached_with_capacity<const N: usize>(&self) -> CachedContractData<N> {
+        CachedContractData::new(self.clone())
+    }
+}
+
+/// Versioned is implemented by contract types that store their version
+/// alongside their fields, by `#[contracttype(version = N)]`.
+///
+/// Values stored by a type without a version are treated as version one
+/// (`1`).
+///
+/// The version is stored in a `_version` field, which is also included in the
+/// contract spec of the type as a `u32`. Values built from the spec must set
+/// `_version` to the version of the type, or they are rejected when they are
+/// converted into the type.
+pub trait Versioned {
+    const VERSION: u32;
+}
+
+/// Migrate converts a value of the previous version of a [Versioned] type
+/// into the current version.
+///
+/// Used by [ContractData::get_migrating] to read values that were stored
+/// before the type gained or changed fields.
+pub trait Migrate: Versioned + Sized {
+    /// The previous version of the type.
+    type Previous: Versioned + TryFromVal<Env, RawVal>;
+
+    /// Convert a value of the previous version into the current version.
+    fn migrate(env: &Env, prev: Self::Previous) -> Self;
+}
+
+/// Returns the version a contract type was stored with, which is one (`1`)
+/// if the type was stored without a version.
+#[doc(hidden)]
+pub fn stored_version(map: &Map<Symbol, EnvVal>) -> Result<u32, ConversionError> {
+    match map.get(VERSION_KEY) {
+        Some(Ok(ev)) => u32::try_from_val(map.env(), ev.val).map_err(|_| ConversionError),
+        Some(Err(_)) => Err(ConversionError),
+        None => Ok(1),
+    }
+}
+
+const VERSION_KEY: Symbol = Symbol::from_str("_version");
+
+/// The number of keys a [CachedContractData] holds by default.
+pub const CACHE_CAPACITY: usize = 16;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EntryState {
+    /// The value was loaded from contract data and has not changed.
+    Clean,
+    /// The value was set and has not been written to contract data.
+    Dirty,
+    /// The key was loaded from contract data and has no value.
+    Absent,
+    /// The key was removed and has not been removed from contract data.
+    Removed,
+}
+
+#[derive(Clone, Copy)]
+struct CacheEntry {
+    key: RawVal,
+    state: EntryState,
+    val: RawVal,
+}
+
+/// CachedContractData caches reads and buffers writes of the currently
+/// executing contracts data.
+///
+/// The cache is held in guest memory and holds up to `N` keys. The first read
+/// of a key loads the value from the contract data, and later reads of the key
+/// are served from the cache without loading it from the ledger again. Writes
+/// and removals are held in the cache until [CachedContractData::commit] is
+/// called or the [CachedContractData] is dropped, at which point only the
+/// keys that changed are written.
+///
+/// When the cache is full, keys that have only been read are evicted to make
+/// room. If every key in the cache has a pending write, all pending writes are
+/// committed before a write to a new key is held in the cache, so that writes
+/// reach the contract data in the order they were made. A read of a new key
+/// when every key has a pending write is loaded without being cached.
+///
+/// Writes made through a [CachedContractData] are not visible through
+/// [ContractData] until they are committed, and writes made through
+/// [ContractData] to keys already in the cache are not visible through the
+/// [CachedContractData].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Env, Symbol};
+///
+/// # use soroban_sdk::{contractimpl, BytesN};
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let mut cache = env.contract_data().cached();
+/// let key = Symbol::from_str("count");
+/// for _ in 0..10 {
+///     let count: u32 = cache.get_or(key, 0);
+///     cache.set(key, count + 1);
+/// }
+/// cache.commit();
+/// assert_eq!(env.contract_data().get_or(key, 0u32), 10);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = BytesN::from_array(&env, [0; 32]);
+/// #     env.register_contract(&contract_id, Contract);
+/// #     f::invoke(&env, &contract_id);
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct CachedContractData<const N: usize = CACHE_CAPACITY> {
+    data: ContractData,
+    entries: [Option<CacheEntry>; N],
+}
+
+impl<const N: usize> Debug for CachedContractData<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CachedContractData")
+    }
+}
+
+impl<const N: usize> CachedContractData<N> {
+    fn new(data: ContractData) -> CachedContractData<N> {
+        CachedContractData {
+            data,
+            entries: [None; N],
+        }
+    }
+
+    /// Returns the index of the entry for the key, if the key is in the
+    /// cache.
+    fn find(&self, key: RawVal) -> Option<usize> {
+        let env = self.data.env();
+        self.entries.iter().position(|e| match e {
+            Some(e) => keys_eq(env, e.key, key),
+            None => false,
+        })
+    }
+
+    /// Returns the index of a slot that a new entry can be stored in,
+    /// evicting an entry without pending changes if the cache is full.
+    fn vacant(&self) -> Option<usize> {
+        self.entries.iter().position(Option::is_none).or_else(|| {
+            self.entries.iter().position(|e| {
+                matches!(
+                    e,
+                    Some(CacheEntry {
+                        state: EntryState::Clean | EntryState::Absent,
+                        ..
+                    })
+                )
+            })
+        })
+    }
+
+    /// Returns the cached state and value of the key, loading it from the
+    /// contract data if it is not in the cache.
+    fn load(&mut self, key: RawVal) -> (EntryState, RawVal) {
+        if let Some(i) = self.find(key) {
+            let e = self.entries[i].unwrap();
+            return (e.state, e.val);
+        }
+        let env = self.data.env();
+        let (state, val) = if self.data.has(key) {
+            (
+                EntryState::Clean,
+                internal::Env::get_contract_data(env, key),
+            )
+        } else {
+            (EntryState::Absent, ().into())
+        };
+        if let Some(i) = self.vacant() {
+            self.entries[i] = Some(CacheEntry { key, state, val });
+        }
+        (state, val)
+    }
+
+    /// Stores the state and value for the key in the cache, committing the
+    /// pending changes first if the cache has no room. A cache with a capacity
+    /// of zero (`0`) applies the change to the contract data immediately.
+    fn store(&mut self, key: RawVal, state: EntryState, val: RawVal) {
+        let slot = self.find(key).or_else(|| self.vacant()).or_else(|| {
+            self.commit();
+            self.vacant()
+        });
+        if let Some(i) = slot {
+            self.entries[i] = Some(CacheEntry { key, state, val });
+            return;
+        }
+        let env = self.data.env();
+        match state {
+            EntryState::Dirty => internal::Env::put_contract_data(env, key, val),
+            _ => internal::Env::del_contract_data(env, key),
+        };
+    }
+
+    /// Returns if there is a value stored for the given key.
+    pub fn has<K>(&mut self, key: K) -> bool
+    where
+        K: IntoVal<Env, RawVal>,
+    {
+        let key = key.into_val(self.data.env());
+        let (state, _) = self.load(key);
+        matches!(state, EntryState::Clean | EntryState::Dirty)
+    }
+
+    /// Returns the value stored for the given key, or [None] if the key does
+    /// not have a value stored.
+    ///
+    /// Returns an error if the value stored cannot be converted into the type
+    /// expected.
+    pub fn try_get<K, V>(&mut self, key: K) -> Result<Option<V>, V::Error>
+    where
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        let key = key.into_val(self.data.env());
+        match self.load(key) {
+            (EntryState::Clean | EntryState::Dirty, val) => {
+                Ok(Some(V::try_from_val(self.data.env(), val)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the value stored for the given key, or [None] if the key does
+    /// not have a value stored.
+    pub fn get<K, V>(&mut self, key: K) -> Option<Result<V, V::Error>>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        self.try_get(key).transpose()
+    }
+
+    /// Returns the value stored for the given key, or the default if the key
+    /// does not have a value stored.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn get_or<K, V>(&mut self, key: K, default: V) -> V
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        V: TryFromVal<Env, RawVal>,
+    {
+        self.try_get(key).unwrap().unwrap_or(default)
+    }
+
+    /// Sets the value for the given key. The value is written to the contract
+    /// data when the cache is committed.
+    pub fn set<K, V>(&mut self, key: K, val: V)
+    where
+        K: IntoVal<Env, RawVal>,
+        V: IntoVal<Env, RawVal>,
+    {
+        let env = self.data.env();
+        let key = key.into_val(env);
+        let val = val.into_val(env);
+        self.store(key, EntryState::Dirty, val);
+    }
+
+    /// Removes the value for the given key. The value is removed from the
+    /// contract data when the cache is committed.
+    pub fn remove<K>(&mut self, key: K)
+    where
+        K: IntoVal<Env, RawVal>,
+    {
+        let key = key.into_val(self.data.env());
+        self.store(key, EntryState::Removed, ().into());
+    }
+
+    /// Writes the values set and removed since the last commit to the
+    /// contract data.
+    pub fn commit(&mut self) {
+        let env = self.data.env();
+        for e in self.entries.iter_mut().flatten() {
+            match e.state {
+                EntryState::Dirty => {
+                    internal::Env::put_contract_data(env, e.key, e.val);
+                    e.state = EntryState::Clean;
+                }
+                EntryState::Removed => {
+                    internal::Env::del_contract_data(env, e.key);
+                    e.state = EntryState::Absent;
+                }
+                EntryState::Clean | EntryState::Absent => (),
+            }
+        }
+    }
+}
+
+impl<const N: usize> Drop for CachedContractData<N> {
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
+
+/// Returns true if the keys are equal, comparing objects by value.
+fn keys_eq(env: &Env, a: RawVal, b: RawVal) -> bool {
+    if a.get_payload() == b.get_payload() {
+        return true;
+    }
+    if Object::try_from(a).is_ok() && Object::try_from(b).is_ok() {
+        let v = internal::Env::obj_cmp(env, a, b);
+        return i32::try_from(v).unwrap() == 0;
+    }
+    false
+}
+
+const INDEX_TAG: Symbol = Symbol::from_str("idx");
+
+/// StorageMap is a map stored in the contract data of the currently executing
+/// contract, with each entry stored under its own contract data key.
+///
+/// Unlike a [Map](crate::Map) stored under a single key, reading or writing
+/// an entry of a [StorageMap] only loads and stores that entry, and so the
+/// cost of accessing a [StorageMap] does not grow with the number of entries.
+///
+/// All entries are stored under keys beginning with the prefix, and the
+/// prefix itself is used to store the length of the map. Two collections
+/// should not share a prefix, and the prefix should not be used as a key for
+/// other contract data.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Env, StorageMap, Symbol};
+///
+/// # use soroban_sdk::{contractimpl, BytesN};
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let map = StorageMap::<u32, i64>::new(&env, Symbol::from_str("balances"));
+/// map.set(&1, &10);
+/// map.set(&2, &20);
+/// assert_eq!(map.get(&1), Some(10));
+/// assert_eq!(map.len(), 2);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = BytesN::from_array(&env, [0; 32]);
+/// #     env.register_contract(&contract_id, Contract);
+/// #     f::invoke(&env, &contract_id);
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct StorageMap<K, V> {
+    data: ContractData,
+    prefix: Symbol,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<K, V> Debug for StorageMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StorageMap({:?})", self.prefix)
+    }
+}
+
+impl<K, V> StorageMap<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    K::Error: Debug,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    V::Error: Debug,
+{
+    /// Creates a [StorageMap] with entries stored under the prefix.
+    pub fn new(env: &Env, prefix: Symbol) -> StorageMap<K, V> {
+        StorageMap {
+            data: env.contract_data(),
+            prefix,
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> u32 {
+        match self.data.get::<_, u32>(self.prefix) {
+            Some(len) => len.unwrap(),
+            None => 0,
+        }
+    }
+
+    /// Returns true if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the map contains the key.
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.data.has((self.prefix, k.clone()))
+    }
+
+    /// Returns the value for the key, or [None] if the map does not contain
+    /// the key.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn get(&self, k: &K) -> Option<V> {
+        self.get_entry(k).map(|(v, _)| v)
+    }
+
+    /// Sets the value for the key, replacing any existing value.
+    pub fn set(&self, k: &K, v: &V) {
+        let i = match self.get_entry(k) {
+            Some((_, i)) => i,
+            None => {
+                let i = self.len();
+                self.data.set((self.prefix, INDEX_TAG, i), k.clone());
+                self.data.set(self.prefix, i + 1);
+                i
+            }
+        };
+        self.data.set((self.prefix, k.clone()), (v.clone(), i));
+    }
+
+    /// Removes the key from the map, returning the value that was stored for
+    /// the key, or [None] if the map did not contain the key.
+    ///
+    /// The last entry of the map takes the position of the removed entry in
+    /// iteration order.
+    pub fn remove(&self, k: &K) -> Option<V> {
+        let (v, i) = self.get_entry(k)?;
+        let last = self.len() - 1;
+        if i != last {
+            let last_k: K = self
+                .data
+                .get_unchecked((self.prefix, INDEX_TAG, last))
+                .unwrap();
+            let (last_v, _) = self.get_entry(&last_k).unwrap();
+            self.data.set((self.prefix, INDEX_TAG, i), last_k.clone());
+            self.data.set((self.prefix, last_k), (last_v, i));
+        }
+        self.data.remove((self.prefix, INDEX_TAG, last));
+        self.data.remove((self.prefix, k.clone()));
+        self.data.set(self.prefix, last);
+        Some(v)
+    }
+
+    /// Returns an iterator over the keys and values of the map.
+    ///
+    /// ### Panics
+    ///
+    /// When a key or value stored cannot be converted into the type expected.
+    pub fn iter(&self) -> StorageMapIter<K, V> {
+        StorageMapIter {
+            map: self.clone(),
+            i: 0,
+            len: self.len(),
+        }
+    }
+
+    fn get_entry(&self, k: &K) -> Option<(V, u32)> {
+        self.data
+            .get::<_, (V, u32)>((self.prefix, k.clone()))
+            .map(|e| e.unwrap())
+    }
+}
+
+/// StorageMapIter iterates over the entries of a [StorageMap] in their
+/// indexed order, reading each entry from contract data as it is reached.
+///
+/// Returned by [StorageMap::iter].
+#[derive(Clone)]
+pub struct StorageMapIter<K, V> {
+    map: StorageMap<K, V>,
+    i: u32,
+    len: u32,
+}
+
+impl<K, V> Iterator for StorageMapIter<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    K::Error: Debug,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    V::Error: Debug,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.len {
+            return None;
+        }
+        let data = &self.map.data;
+        let k: K = data
+            .get_unchecked((self.map.prefix, INDEX_TAG, self.i))
+            .unwrap();
+        let v = self.map.get(&k).unwrap();
+        self.i += 1;
+        Some((k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.len - self.i) as usize;
+        (len, Some(len))
+    }
+}
+
+/// StorageVec is a vector stored in the contract data of the currently
+/// executing contract, with each element stored under its own contract data
+/// key.
+///
+/// Unlike a [Vec](crate::Vec) stored under a single key, reading or writing
+/// an element of a [StorageVec] only loads and stores that element.
+///
+/// All elements are stored under keys beginning with the prefix, and the
+/// prefix itself is used to store the length of the vector. Two collections
+/// should not share a prefix, and the prefix should not be used as a key for
+/// other contract data.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Env, StorageVec, Symbol};
+///
+/// # use soroban_sdk::{contractimpl, BytesN};
+/// #
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) {
+/// let vec = StorageVec::<u32>::new(&env, Symbol::from_str("items"));
+/// vec.push(&1);
+/// vec.push(&2);
+/// assert_eq!(vec.get(1), Some(2));
+/// assert_eq!(vec.pop(), Some(2));
+/// assert_eq!(vec.len(), 1);
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = BytesN::from_array(&env, [0; 32]);
+/// #     env.register_contract(&contract_id, Contract);
+/// #     f::invoke(&env, &contract_id);
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct StorageVec<T> {
+    data: ContractData,
+    prefix: Symbol,
+    _t: PhantomData<T>,
+}
+
+impl<T> Debug for StorageVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StorageVec({:?})", self.prefix)
+    }
+}
+
+impl<T> StorageVec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    T::Error: Debug,
+{
+    /// Creates a [StorageVec] with elements stored under the prefix.
+    pub fn new(env: &Env, prefix: Symbol) -> StorageVec<T> {
+        StorageVec {
+            data: env.contract_data(),
+            prefix,
+            _t: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> u32 {
+        match self.data.get::<_, u32>(self.prefix) {
+            Some(len) => len.unwrap(),
+            None => 0,
+        }
+    }
+
+    /// Returns true if the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at the index, or [None] if the index is out of
+    /// bounds.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn get(&self, i: u32) -> Option<T> {
+        if i < self.len() {
+            Some(self.data.get_unchecked((self.prefix, i)).unwrap())
+        } else {
+            None
+        }
+    }
+
+    /// Sets the element at the index.
+    ///
+    /// ### Panics
+    ///
+    /// When the index is out of bounds.
+    pub fn set(&self, i: u32, v: &T) {
+        if i >= self.len() {
+            panic!("index out of bounds");
+        }
+        self.data.set((self.prefix, i), v.clone());
+    }
+
+    /// Appends the element to the back of the vector.
+    pub fn push(&self, v: &T) {
+        let len = self.len();
+        self.data.set((self.prefix, len), v.clone());
+        self.data.set(self.prefix, len + 1);
+    }
+
+    /// Removes the last element of the vector and returns it, or [None] if
+    /// the vector is empty.
+    pub fn pop(&self) -> Option<T> {
+        let len = self.len();
+        let last = len.checked_sub(1)?;
+        let v = self.get(last);
+        self.data.remove((self.prefix, last));
+        self.data.set(self.prefix, last);
+        v
+    }
+
+    /// Removes the element at the index and returns it, shifting all
+    /// elements after it towards the front. Returns [None] if the index is
+    /// out of bounds.
+    ///
+    /// Every element after the index is loaded and stored again. Use
+    /// [StorageVec::swap_remove] when order does not need to be preserved.
+    pub fn remove(&self, i: u32) -> Option<T> {
+        let removed = self.get(i)?;
+        let len = self.len();
+        for j in i + 1..len {
+            let v: T = self.get(j).unwrap();
+            self.data.set((self.prefix, j - 1), v);
+        }
+        self.data.remove((self.prefix, len - 1));
+        self.data.set(self.prefix, len - 1);
+        Some(removed)
+    }
+
+    /// Removes the element at the index and returns it, replacing it with the
+    /// last element of the vector. Returns [None] if the index is out of
+    /// bounds.
+    pub fn swap_remove(&self, i: u32) -> Option<T> {
+        let removed = self.get(i)?;
+        let last = self.len() - 1;
+        if i != last {
+            let v: T = self.get(last).unwrap();
+            self.data.set((self.prefix, i), v);
+        }
+        self.data.remove((self.prefix, last));
+        self.data.set(self.prefix, last);
+        Some(removed)
+    }
+
+    /// Returns an iterator over the elements of the vector.
+    ///
+    /// ### Panics
+    ///
+    /// When an element stored cannot be converted into the type expected.
+    pub fn iter(&self) -> StorageVecIter<T> {
+        StorageVecIter {
+            vec: self.clone(),
+            i: 0,
+            len: self.len(),
+        }
+    }
+}
+
+/// StorageVecIter iterates over the elements of a [StorageVec], from the
+/// first to the last, reading each element from contract data as it is
+/// reached.
+///
+/// Returned by [StorageVec::iter].
+#[derive(Clone)]
+pub struct StorageVecIter<T> {
+    vec: StorageVec<T>,
+    i: u32,
+    len: u32,
+}
+
+impl<T> Iterator for StorageVecIter<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
+    T::Error: Debug,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.len {
+            return None;
+        }
+        let v = self.vec.get(self.i);
+        self.i += 1;
+        v
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.len - self.i) as usize;
+        (len, Some(len))
+    }
 }
@@ -43,7 +43,10 @@ pub type EnvVal = internal::EnvVal<Env, RawVal>;
 pub type EnvObj = internal::EnvVal<Env, Object>;
 
 use crate::bytes::{Bytes, BytesN};
-use crate::ContractData;
+use crate::{ContractData, Identifier};
+
+const INVOKER_TYPE_ACCOUNT: u32 = 0;
+const INVOKER_TYPE_CONTRACT: u32 = 1;
 
 /// The [Env] type provides access to the environment the contract is executing
 /// within.
@@ -85,9 +88,8 @@ impl Env {
     /// Will also panic if the value returned from the contract cannot be
     /// converted into the type `T`.
     ///
-    /// ### TODO
-    ///
-    /// Return a [Result] instead of panic.
+    /// Use [try_invoke_contract](Env::try_invoke_contract) to receive a
+    /// [Result] instead.
     pub fn invoke_contract<T: TryFromVal<Env, RawVal>>(
         &self,
         contract_id: &BytesN<32>,
@@ -98,6 +100,31 @@ impl Env {
         T::try_from_val(&self, rv).map_err(|_| ()).unwrap()
     }
 
+    /// Invokes a function of a contract that is registered in the [Env],
+    /// returning a [Status] if the invocation fails.
+    ///
+    /// The [Status] is returned as an `Err` if the contract does not exist, the
+    /// function does not exist, the function fails, or if the value returned
+    /// cannot be converted into the type `T`.
+    ///
+    /// Any [Status] value returned by the function is also returned as an
+    /// `Err`, including a [Status] that the function returns on purpose
+    /// rather than by failing, so `T` cannot be [Status] and a function that
+    /// succeeds with a [Status] cannot be told apart from one that fails with
+    /// it.
+    pub fn try_invoke_contract<T: TryFromVal<Env, RawVal>>(
+        &self,
+        contract_id: &BytesN<32>,
+        func: &Symbol,
+        args: crate::vec::Vec<EnvVal>,
+    ) -> Result<T, Status> {
+        let rv = internal::Env::try_call(self, contract_id.to_object(), *func, args.to_object());
+        if let Ok(status) = Status::try_from(rv) {
+            return Err(status);
+        }
+        T::try_from_val(self, rv).map_err(|_| xdr::ScHostValErrorCode::UnexpectedValType.into())
+    }
+
     /// Get a [ContractData] for accessing and update contract data that has
     /// been stored by the currently executing contract.
     #[inline(always)]
@@ -118,13 +145,48 @@ impl Env {
     ///
     /// # Panics
     ///
-    /// Will panic the contract was not invoked by another contract.
+    /// Will panic the contract was not invoked by another contract. Use
+    /// [Env::invoking_contract] or [Env::invoker] when the contract may be
+    /// invoked directly by a transaction.
     pub fn get_invoking_contract(&self) -> BytesN<32> {
         let rv = internal::Env::get_invoking_contract(self).to_raw();
         let bin = Bytes::try_from_val(self, rv).unwrap();
         bin.try_into().unwrap()
     }
 
+    /// Get the 32-byte hash identifier of the contract that invoked this
+    /// contract, or [None] if the contract was invoked directly by a
+    /// transaction.
+    pub fn invoking_contract(&self) -> Option<BytesN<32>> {
+        if self.invoker_type() == INVOKER_TYPE_CONTRACT {
+            Some(self.get_invoking_contract())
+        } else {
+            None
+        }
+    }
+
+    /// Get the [Identifier] of the account or contract that invoked the
+    /// currently executing contract.
+    ///
+    /// The invoker is an [Identifier::Account] when the contract was invoked
+    /// directly by a transaction, in which case it is the source account of
+    /// the transaction, and an [Identifier::Contract] when the contract was
+    /// invoked by another contract.
+    pub fn invoker(&self) -> Identifier {
+        match self.invoker_type() {
+            INVOKER_TYPE_ACCOUNT => {
+                let obj = internal::Env::get_invoking_account(self);
+                Identifier::Account(obj.in_env(self).try_into().unwrap())
+            }
+            INVOKER_TYPE_CONTRACT => Identifier::Contract(self.get_invoking_contract()),
+            _ => panic!("unknown invoker type"),
+        }
+    }
+
+    fn invoker_type(&self) -> u32 {
+        internal::Env::get_invoker_type(self).try_into().unwrap()
+    }
+
     #[doc(hidden)]
     #[deprecated(note = "use contract_data().has(key)")]
     pub fn has_contract_data<K>(&self, key: K) -> bool
@@ -272,6 +334,27 @@ impl Env {
         }
     }
 
+    pub(crate) fn host(&self) -> &internal::EnvImpl {
+        &self.env_impl
+    }
+
+    /// Returns the Stellar accounts in the test ledger of the [Env], which
+    /// can be created and modified for testing contracts that use
+    /// [Account](crate::Account).
+    pub fn accounts(&self) -> crate::testutils::accounts::Accounts {
+        crate::testutils::accounts::Accounts::new(self)
+    }
+
+    /// Set the source account of the transaction that contracts are invoked
+    /// by in the [Env], which is the invoker of contracts invoked directly
+    /// from tests.
+    pub fn set_source_account(&self, pk: &BytesN<32>) {
+        let id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+            pk.to_array(),
+        )));
+        self.env_impl.set_source_account(id);
+    }
+
     /// Register a contract with the [Env] for testing.
     ///
     /// ### Examples
@@ -341,6 +424,24 @@ impl Env {
         (deep_clone, res)
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn last_debug_event_has_status(&self, status: Status) -> bool {
+        use soroban_env_host::events::{DebugArg, HostEvent};
+
+        if let Some(HostEvent::Debug(dbg)) = self.env_impl.get_events().0.last() {
+            for arg in dbg.args.iter() {
+                if let DebugArg::Val(v) = arg {
+                    if let Ok(st) = TryInto::<Status>::try_into(*v) {
+                        if st == status {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
     #[cfg(not(target_family = "wasm"))]
     pub fn assert_panic_with_string<F, T: Debug>(&self, s: &str, f: F)
     where
@@ -366,8 +467,6 @@ impl Env {
     where
         F: FnOnce(Env) -> T,
     {
-        use soroban_env_host::events::{DebugArg, HostEvent};
-
         match self.clone_self_and_catch_panic(f) {
             (_, Ok(v)) => panic!("inner function expected to panic, but returned {:?}", v),
             (clone, Err(e)) => {
@@ -377,16 +476,8 @@ impl Env {
                     return;
                 }
                 // Allow if the last debug log entry contains the status of requested.
-                if let Some(HostEvent::Debug(dbg)) = clone.env_impl.get_events().0.last() {
-                    for arg in dbg.args.iter() {
-                        if let DebugArg::Val(v) = arg {
-                            if let Ok(st) = TryInto::<Status>::try_into(*v) {
-                                if st == status {
-                                    return;
-                                }
-                            }
-                        }
-                    }
+                if clone.last_debug_event_has_status(status) {
+                    return;
                 }
 
                 // Otherwise we're going to fail but we'll try to produce a useful diagnostic if
@@ -435,16 +526,16 @@ impl internal::EnvBase for Env {
         }
     }
 
-    fn binary_copy_from_slice(&self, _: Object, _: RawVal, _: &[u8]) -> Object {
-        unimplemented!()
+    fn binary_copy_from_slice(&self, b: Object, b_pos: RawVal, mem: &[u8]) -> Object {
+        internal::EnvBase::binary_copy_from_slice(&self.env_impl, b, b_pos, mem)
     }
 
-    fn binary_copy_to_slice(&self, _: Object, _: RawVal, _: &mut [u8]) {
-        unimplemented!()
+    fn binary_copy_to_slice(&self, b: Object, b_pos: RawVal, mem: &mut [u8]) {
+        internal::EnvBase::binary_copy_to_slice(&self.env_impl, b, b_pos, mem)
     }
 
-    fn binary_new_from_slice(&self, _: &[u8]) -> Object {
-        unimplemented!()
+    fn binary_new_from_slice(&self, mem: &[u8]) -> Object {
+        internal::EnvBase::binary_new_from_slice(&self.env_impl, mem)
     }
 
     fn log_static_fmt_val(&self, _: &'static str, _: RawVal) {
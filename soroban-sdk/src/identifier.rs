@@ -0,0 +1,311 @@
+#[cfg(not(target_family = "wasm"))]
+use crate::env::internal::xdr::ScVal;
+use core::{cmp::Ordering, fmt::Display};
+
+use crate::{
+    env::internal::{Env as _, RawVal, TryIntoVal},
+    strkey, Account, BytesN, ConversionError, Env, EnvType, EnvVal, IntoVal, Symbol,
+};
+#[cfg(not(target_family = "wasm"))]
+use crate::{Object, Vec};
+
+/// Identifier identifies an account or contract that can call a contract or
+/// be referenced by a contract.
+///
+/// An [Identifier] is stored and passed to contracts the same way a
+/// `#[contracttype]` enum with the same variants would be, as a vector of the
+/// variant name and the variant's value. In contract specs it is the
+/// user-defined union type `Identifier`, and its spec is included in the spec
+/// of a contract by each contract function and contract type that references
+/// it.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Account, BytesN, Env, Identifier};
+///
+/// let env = Env::default();
+/// let id = Identifier::Contract(BytesN::from_array(&env, [0; 32]));
+/// assert!(id.is_contract());
+/// assert!(id < Identifier::Contract(BytesN::from_array(&env, [1; 32])));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, soroban_sdk_macros::ContractSpec)]
+pub enum Identifier {
+    /// A contract, identified by the hash of the contract.
+    Contract(BytesN<32>),
+    /// An ed25519 public key that is not necessarily a Stellar account.
+    Ed25519(BytesN<32>),
+    /// A Stellar account.
+    Account(Account),
+}
+
+const DISCRIMINANT_SYM_CONTRACT: Symbol = Symbol::from_str("Contract");
+const DISCRIMINANT_SYM_ED25519: Symbol = Symbol::from_str("Ed25519");
+const DISCRIMINANT_SYM_ACCOUNT: Symbol = Symbol::from_str("Account");
+const DISCRIMINANT_U64_CONTRACT: u64 = DISCRIMINANT_SYM_CONTRACT.to_raw().get_payload();
+const DISCRIMINANT_U64_ED25519: u64 = DISCRIMINANT_SYM_ED25519.to_raw().get_payload();
+const DISCRIMINANT_U64_ACCOUNT: u64 = DISCRIMINANT_SYM_ACCOUNT.to_raw().get_payload();
+
+impl TryFrom<EnvVal> for Identifier {
+    type Error = ConversionError;
+
+    fn try_from(ev: EnvVal) -> Result<Self, Self::Error> {
+        let (discriminant, value): (Symbol, EnvVal) = ev.try_into()?;
+        Ok(match discriminant.to_raw().get_payload() {
+            DISCRIMINANT_U64_CONTRACT => Identifier::Contract(value.try_into()?),
+            DISCRIMINANT_U64_ED25519 => Identifier::Ed25519(value.try_into()?),
+            DISCRIMINANT_U64_ACCOUNT => Identifier::Account(value.try_into()?),
+            _ => return Err(ConversionError),
+        })
+    }
+}
+
+impl TryIntoVal<Env, Identifier> for RawVal {
+    type Error = ConversionError;
+
+    fn try_into_val(self, env: &Env) -> Result<Identifier, Self::Error> {
+        EnvType {
+            env: env.clone(),
+            val: self,
+        }
+        .try_into()
+    }
+}
+
+impl IntoVal<Env, RawVal> for Identifier {
+    fn into_val(self, env: &Env) -> RawVal {
+        match self {
+            Identifier::Contract(b) => (DISCRIMINANT_SYM_CONTRACT, RawVal::from(b)).into_val(env),
+            Identifier::Ed25519(b) => (DISCRIMINANT_SYM_ED25519, RawVal::from(b)).into_val(env),
+            Identifier::Account(a) => (DISCRIMINANT_SYM_ACCOUNT, RawVal::from(a)).into_val(env),
+        }
+    }
+}
+
+impl From<Identifier> for EnvVal {
+    fn from(id: Identifier) -> Self {
+        let env = id.env().clone();
+        let val = id.into_val(&env);
+        EnvVal { env, val }
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let env = self.env();
+        let a: RawVal = self.clone().into_val(env);
+        let b: RawVal = other.clone().into_val(env);
+        let v = env.obj_cmp(a, b);
+        let i = i32::try_from(v).unwrap();
+        i.cmp(&0)
+    }
+}
+
+/// Formats the strkey of the identifier tagged with its variant, such as
+/// `Ed25519(G...)`, so that keys and accounts that have the same strkey can be
+/// told apart.
+impl Display for Identifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let variant = match self {
+            Identifier::Contract(_) => "Contract",
+            Identifier::Ed25519(_) => "Ed25519",
+            Identifier::Account(_) => "Account",
+        };
+        write!(f, "{}({})", variant, self.to_strkey())
+    }
+}
+
+impl From<Account> for Identifier {
+    fn from(a: Account) -> Self {
+        Identifier::Account(a)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<&Identifier> for ScVal {
+    type Error = ConversionError;
+    fn try_from(v: &Identifier) -> Result<Self, Self::Error> {
+        let env = v.env();
+        let val: RawVal = v.clone().into_val(env);
+        let vec: Vec<EnvVal> = val.try_into_val(env)?;
+        (&vec).try_into()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<Identifier> for ScVal {
+    type Error = ConversionError;
+    fn try_from(v: Identifier) -> Result<Self, Self::Error> {
+        (&v).try_into()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryIntoVal<Env, Identifier> for ScVal {
+    type Error = ConversionError;
+    fn try_into_val(self, env: &Env) -> Result<Identifier, Self::Error> {
+        let o: Object = self.try_into_val(env).map_err(|_| ConversionError)?;
+        RawVal::from(o).try_into_val(env)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<EnvType<ScVal>> for Identifier {
+    type Error = ConversionError;
+    fn try_from(v: EnvType<ScVal>) -> Result<Self, Self::Error> {
+        ScVal::try_into_val(v.val, &v.env)
+    }
+}
+
+impl Identifier {
+    fn env(&self) -> &Env {
+        match self {
+            Identifier::Contract(b) | Identifier::Ed25519(b) => b.env(),
+            Identifier::Account(a) => a.env(),
+        }
+    }
+
+    /// Returns true if the [Identifier] is a contract.
+    pub fn is_contract(&self) -> bool {
+        matches!(self, Identifier::Contract(_))
+    }
+
+    /// Returns true if the [Identifier] is a Stellar account.
+    pub fn is_account(&self) -> bool {
+        matches!(self, Identifier::Account(_))
+    }
+
+    /// Returns the strkey of the identifier, a contract strkey (`C...`) for
+    /// contracts and an account strkey (`G...`) for accounts and keys.
+    pub fn to_strkey(&self) -> strkey::StrKey {
+        match self {
+            Identifier::Contract(b) => b.to_contract_strkey(),
+            Identifier::Ed25519(b) => b.to_account_strkey(),
+            Identifier::Account(a) => a.to_strkey(),
+        }
+    }
+
+    /// Returns the 32 bytes that identify the account, contract or key.
+    pub fn to_bytes(&self) -> BytesN<32> {
+        match self {
+            Identifier::Contract(b) | Identifier::Ed25519(b) => b.clone(),
+            Identifier::Account(a) => AsRef::<BytesN<32>>::as_ref(a).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Map;
+
+    #[test]
+    fn test_roundtrip() {
+        let env = Env::default();
+        let b = BytesN::from_array(&env, [1; 32]);
+        let a: Account = [2; 32].into_val(&env);
+        for id in [
+            Identifier::Contract(b.clone()),
+            Identifier::Ed25519(b),
+            Identifier::Account(a),
+        ] {
+            let val: RawVal = id.clone().into_val(&env);
+            let rt: Identifier = val.try_into_val(&env).unwrap();
+            assert_eq!(id, rt);
+        }
+    }
+
+    #[test]
+    fn test_spec() {
+        use crate::xdr::{ReadXdr, ScSpecEntry, ScSpecTypeDef};
+
+        let mut cursor = std::io::Cursor::new(&__SPEC_XDR_IDENTIFIER[..]);
+        let entry = ScSpecEntry::read_xdr(&mut cursor).unwrap();
+        let union = match entry {
+            ScSpecEntry::UdtUnionV0(u) => u,
+            _ => panic!("expected union spec"),
+        };
+        assert_eq!(union.name.to_string().unwrap(), "Identifier");
+        let cases: std::vec::Vec<_> = union
+            .cases
+            .iter()
+            .map(|c| (c.name.to_string().unwrap(), c.type_.clone()))
+            .collect();
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[0].0, "Contract");
+        assert_eq!(cases[0].1, Some(ScSpecTypeDef::Binary));
+        assert_eq!(cases[1].0, "Ed25519");
+        assert_eq!(cases[2].0, "Account");
+    }
+
+    #[test]
+    fn test_display() {
+        let env = Env::default();
+        let b = BytesN::from_array(&env, [1; 32]);
+        let a: Account = [1; 32].into_val(&env);
+        let strkey = b.to_account_strkey();
+
+        let ed25519 = Identifier::Ed25519(b.clone());
+        let account = Identifier::Account(a);
+        assert_eq!(format!("{}", ed25519), format!("Ed25519({})", strkey));
+        assert_eq!(format!("{}", account), format!("Account({})", strkey));
+        assert_ne!(format!("{}", ed25519), format!("{}", account));
+        assert_eq!(
+            format!("{}", Identifier::Contract(b.clone())),
+            format!("Contract({})", b.to_contract_strkey())
+        );
+    }
+
+    #[test]
+    fn test_wrong_discriminant() {
+        let env = Env::default();
+        let b = BytesN::from_array(&env, [1; 32]);
+        let val: RawVal = (Symbol::from_str("Other"), RawVal::from(b)).into_val(&env);
+        let res: Result<Identifier, _> = val.try_into_val(&env);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_ord() {
+        let env = Env::default();
+        let b1 = BytesN::from_array(&env, [1; 32]);
+        let b2 = BytesN::from_array(&env, [2; 32]);
+        assert!(Identifier::Contract(b1.clone()) < Identifier::Contract(b2.clone()));
+        assert!(Identifier::Ed25519(b1) < Identifier::Ed25519(b2));
+    }
+
+    #[test]
+    fn test_ord_matches_host() {
+        let env = Env::default();
+        let b1 = BytesN::from_array(&env, [1; 32]);
+        let b2 = BytesN::from_array(&env, [2; 32]);
+        let a: Account = [3; 32].into_val(&env);
+        let ids = [
+            Identifier::Account(a),
+            Identifier::Ed25519(b2.clone()),
+            Identifier::Contract(b2),
+            Identifier::Ed25519(b1.clone()),
+            Identifier::Contract(b1),
+        ];
+
+        let mut map = Map::<Identifier, u32>::new(&env);
+        for (i, id) in ids.iter().enumerate() {
+            map.set(id.clone(), i as u32);
+        }
+        let keys: std::vec::Vec<Identifier> = map.keys().iter().map(Result::unwrap).collect();
+        assert_eq!(keys.len(), ids.len());
+        for w in keys.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+
+        let mut sorted = ids.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, keys);
+    }
+}
@@ -2,6 +2,41 @@ use core::fmt::Debug;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 
+use crate::Env;
+
+#[cfg(doc)]
+use crate::{Bytes, Map, Vec};
+
+/// FromIteratorIn is implemented by collections that can be created from an
+/// iterator in an [Env], such as [Vec], [Map] and [Bytes].
+///
+/// The standard [FromIterator] cannot be used by these collections because
+/// they need an [Env] to be created.
+pub trait FromIteratorIn<T>: Sized {
+    fn from_iter_in<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Self;
+}
+
+/// CollectIn collects the items of an iterator into a collection that needs
+/// an [Env] to be created, such as [Vec], [Map] and [Bytes].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{iter::CollectIn, vec, Env, Vec};
+///
+/// let env = Env::default();
+/// let v: Vec<u32> = (1..4).map(|x| x * 10).collect_in(&env);
+/// assert_eq!(v, vec![&env, 10, 20, 30]);
+/// ```
+pub trait CollectIn: Iterator + Sized {
+    #[inline(always)]
+    fn collect_in<C: FromIteratorIn<Self::Item>>(self, env: &Env) -> C {
+        C::from_iter_in(env, self)
+    }
+}
+
+impl<I: Iterator> CollectIn for I {}
+
 pub trait UncheckedEnumerable<I, T, E> {
     fn unchecked(self) -> UncheckedIter<I, T, E>;
 }
@@ -33,7 +33,9 @@ fn __link_sections() {
     static __ENV_META_XDR: [u8; env::meta::XDR.len()] = env::meta::XDR;
 }
 
-pub use soroban_sdk_macros::{contractimpl, contracttype, ContractType};
+pub use soroban_sdk_macros::{
+    bytesn, contractimpl, contractstorage, contracttype, symbol, ContractType,
+};
 
 mod env;
 
@@ -63,18 +65,33 @@ mod account;
 mod bigint;
 mod bytes;
 mod contract_data;
+mod identifier;
 pub mod iter;
 mod map;
+mod string;
+mod symbol;
 mod vec;
-pub use account::Account;
-pub use bigint::BigInt;
+pub use account::{Account, AccountError, Threshold};
+pub use bigint::{BigInt, Sign};
 #[allow(deprecated)]
 pub use bytes::{Binary, FixedBinary};
 pub use bytes::{Bytes, BytesN};
-pub use contract_data::ContractData;
+#[doc(hidden)]
+pub use contract_data::stored_version;
+pub use contract_data::{
+    CachedContractData, ContractData, Migrate, StorageMap, StorageMapIter, StorageVec,
+    StorageVecIter, Versioned, CACHE_CAPACITY,
+};
+pub use identifier::Identifier;
+#[doc(hidden)]
+pub use identifier::__SPEC_XDR_IDENTIFIER;
 pub use map::Map;
+pub use string::String;
+pub use symbol::{SymbolExt, SYMBOL_MAX_LEN};
 pub use vec::Vec;
 
+pub mod prelude;
 pub mod serde;
+pub mod strkey;
 
 pub mod testutils;
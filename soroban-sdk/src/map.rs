@@ -1,16 +1,22 @@
-use core::{cmp::Ordering, fmt::Debug, iter::FusedIterator, marker::PhantomData};
+use core::{
+    cmp::Ordering,
+    fmt::Debug,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
 
-use crate::iter::{UncheckedEnumerable, UncheckedIter};
+use crate::iter::{FromIteratorIn, UncheckedEnumerable, UncheckedIter};
 
 use super::{
     env::internal::Env as _,
-    env::{EnvObj, EnvType},
+    env::{EnvObj, EnvType, Object},
     xdr::ScObjectType,
     ConversionError, Env, EnvVal, IntoVal, RawVal, Status, TryFromVal, TryIntoVal, Vec,
 };
 
 #[cfg(not(target_family = "wasm"))]
-use super::{env::Object, xdr::ScVal};
+use super::xdr::ScVal;
 
 #[cfg(doc)]
 use crate::ContractData;
@@ -284,6 +290,16 @@ where
         map
     }
 
+    /// Create a map containing the key-value pairs of the iterator.
+    ///
+    /// If a key occurs more than once, the last value for the key is kept.
+    #[inline(always)]
+    pub fn from_iter<I: IntoIterator<Item = (K, V)>>(env: &Env, iter: I) -> Map<K, V> {
+        let mut map = Map::<K, V>::new(env);
+        map.extend(iter);
+        map
+    }
+
     #[inline(always)]
     pub fn contains_key(&self, k: K) -> bool {
         let env = self.env();
@@ -367,6 +383,180 @@ where
         Vec::<V>::try_from_val(env, vec).unwrap()
     }
 
+    /// Returns the smallest key in the map, or [None] if the map is empty.
+    #[inline(always)]
+    pub fn min_key(&self) -> Option<Result<K, K::Error>> {
+        let env = self.env();
+        min_key(env, self.0.to_object()).map(|k| K::try_from_val(env, k))
+    }
+
+    /// Returns the largest key in the map, or [None] if the map is empty.
+    #[inline(always)]
+    pub fn max_key(&self) -> Option<Result<K, K::Error>> {
+        let env = self.env();
+        max_key(env, self.0.to_object()).map(|k| K::try_from_val(env, k))
+    }
+
+    /// Returns the smallest key in the map that is greater than the given
+    /// key, or [None] if there is no such key.
+    ///
+    /// The given key does not need to be in the map.
+    #[inline(always)]
+    pub fn next_key(&self, k: K) -> Option<Result<K, K::Error>> {
+        let env = self.env();
+        next_key(env, self.0.to_object(), k.into_val(env)).map(|k| K::try_from_val(env, k))
+    }
+
+    /// Returns the largest key in the map that is less than the given key,
+    /// or [None] if there is no such key.
+    ///
+    /// The given key does not need to be in the map.
+    #[inline(always)]
+    pub fn prev_key(&self, k: K) -> Option<Result<K, K::Error>> {
+        let env = self.env();
+        prev_key(env, self.0.to_object(), k.into_val(env)).map(|k| K::try_from_val(env, k))
+    }
+
+    /// Returns an iterator over the entries of the map with keys within the
+    /// range, in the order of the keys.
+    ///
+    /// The bounds of the range do not need to be keys in the map. The first
+    /// and last keys of the range are found using the ordering of the host,
+    /// and entries outside of the range are not visited.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, Map, map};
+    ///
+    /// let env = Env::default();
+    /// let map = map![&env, (1, 10), (3, 30), (5, 50), (7, 70)];
+    /// let mut range = map.range(2..6);
+    /// assert_eq!(range.next(), Some(Ok((3, 30))));
+    /// assert_eq!(range.next(), Some(Ok((5, 50))));
+    /// assert_eq!(range.next(), None);
+    /// ```
+    pub fn range<R>(&self, range: R) -> MapRange<K, V>
+    where
+        K: Clone,
+        R: RangeBounds<K>,
+    {
+        let env = self.env();
+        let obj = self.0.to_object();
+        let front = match range.start_bound() {
+            Bound::Included(k) => {
+                let k = k.clone().into_val(env);
+                if env.map_has(obj, k).is_true() {
+                    Some(k)
+                } else {
+                    next_key(env, obj, k)
+                }
+            }
+            Bound::Excluded(k) => next_key(env, obj, k.clone().into_val(env)),
+            Bound::Unbounded => min_key(env, obj),
+        };
+        let back = match range.end_bound() {
+            Bound::Included(k) => {
+                let k = k.clone().into_val(env);
+                if env.map_has(obj, k).is_true() {
+                    Some(k)
+                } else {
+                    prev_key(env, obj, k)
+                }
+            }
+            Bound::Excluded(k) => prev_key(env, obj, k.clone().into_val(env)),
+            Bound::Unbounded => max_key(env, obj),
+        };
+        let bounds = match (front, back) {
+            (Some(front), Some(back)) if cmp_keys(env, front, back) != Ordering::Greater => {
+                Some((front, back))
+            }
+            _ => None,
+        };
+        MapRange {
+            map: self.clone(),
+            bounds,
+        }
+    }
+
+    /// Returns the entry for the key in the map, for inserting or modifying
+    /// the value of the key.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, Map, map};
+    ///
+    /// let env = Env::default();
+    /// let mut map = map![&env, (1, 10)];
+    /// assert_eq!(map.entry(1).and_modify(|v| *v += 1).or_insert(0), 11);
+    /// assert_eq!(map.entry(2).and_modify(|v| *v += 1).or_insert(0), 0);
+    /// assert_eq!(map, map![&env, (1, 11), (2, 0)]);
+    /// ```
+    #[inline(always)]
+    pub fn entry(&mut self, k: K) -> MapEntry<K, V> {
+        let key = k.into_val(self.env());
+        MapEntry { map: self, key }
+    }
+
+    /// Removes all entries from the map for which the function returns
+    /// false.
+    ///
+    /// ### Panics
+    ///
+    /// When a key or value in the map cannot be converted into the type
+    /// expected.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: Clone,
+        V: Clone,
+        F: FnMut(&K, &V) -> bool,
+    {
+        for entry in self.iter() {
+            let (k, v) = entry.unwrap();
+            if !f(&k, &v) {
+                self.remove_unchecked(k);
+            }
+        }
+    }
+
+    /// Returns a new map containing the entries of the map for which the
+    /// function returns true.
+    ///
+    /// ### Panics
+    ///
+    /// When a key or value in the map cannot be converted into the type
+    /// expected.
+    pub fn filter<F>(&self, mut f: F) -> Map<K, V>
+    where
+        K: Clone,
+        V: Clone,
+        F: FnMut(&K, &V) -> bool,
+    {
+        let mut map = Map::new(self.env());
+        for entry in self.iter() {
+            let (k, v) = entry.unwrap();
+            if f(&k, &v) {
+                map.set(k, v);
+            }
+        }
+        map
+    }
+
+    /// Sets all the entries of the other map in the map, replacing the
+    /// values of keys that are in both maps with the values of the other
+    /// map.
+    pub fn merge(&mut self, other: &Map<K, V>) {
+        let env = self.env().clone();
+        let other = other.0.to_object();
+        let mut next = min_key(&env, other);
+        while let Some(k) = next {
+            let v = env.map_get(other, k);
+            self.0 = env.map_put(self.0.to_object(), k, v).in_env(&env);
+            next = next_key(&env, other, k);
+        }
+    }
+
     pub fn iter(&self) -> MapIter<K, V>
     where
         K: Clone,
@@ -398,6 +588,145 @@ where
     }
 }
 
+impl<K, V> Extend<(K, V)> for Map<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.set(k, v);
+        }
+    }
+}
+
+impl<K, V> FromIteratorIn<(K, V)> for Map<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn from_iter_in<I: IntoIterator<Item = (K, V)>>(env: &Env, iter: I) -> Self {
+        Map::from_iter(env, iter)
+    }
+}
+
+/// MapEntry is the entry for a key in a [Map], returned by [Map::entry].
+pub struct MapEntry<'a, K, V> {
+    map: &'a mut Map<K, V>,
+    key: RawVal,
+}
+
+impl<'a, K, V> MapEntry<'a, K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    /// Returns the key of the entry.
+    ///
+    /// ### Panics
+    ///
+    /// When the key cannot be converted back into the type expected.
+    pub fn key(&self) -> K
+    where
+        K::Error: Debug,
+    {
+        K::try_from_val(self.map.env(), self.key).unwrap()
+    }
+
+    /// Returns true if the key has a value in the map.
+    pub fn is_occupied(&self) -> bool {
+        let env = self.map.env();
+        env.map_has(self.map.0.to_object(), self.key).is_true()
+    }
+
+    /// Modifies the value of the key with the function if the key has a
+    /// value in the map.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        V::Error: Debug,
+        F: FnOnce(&mut V),
+    {
+        if let Some(mut v) = self.get() {
+            f(&mut v);
+            self.put(v);
+        }
+        self
+    }
+
+    /// Returns the value of the key, inserting the default if the key does
+    /// not have a value in the map.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn or_insert(self, default: V) -> V
+    where
+        V::Error: Debug,
+        V: Clone,
+    {
+        self.or_insert_with(|| default)
+    }
+
+    /// Returns the value of the key, inserting the value returned by the
+    /// function if the key does not have a value in the map.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn or_insert_with<F>(self, f: F) -> V
+    where
+        V::Error: Debug,
+        V: Clone,
+        F: FnOnce() -> V,
+    {
+        match self.get() {
+            Some(v) => v,
+            None => {
+                let v = f();
+                self.put(v.clone());
+                v
+            }
+        }
+    }
+
+    /// Returns the value of the key, inserting the default value of the type
+    /// if the key does not have a value in the map.
+    ///
+    /// ### Panics
+    ///
+    /// When the value stored cannot be converted into the type expected.
+    pub fn or_default(self) -> V
+    where
+        V::Error: Debug,
+        V: Clone + Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    fn get(&self) -> Option<V>
+    where
+        V::Error: Debug,
+    {
+        let env = self.map.env();
+        let obj = self.map.0.to_object();
+        if env.map_has(obj, self.key).is_true() {
+            Some(V::try_from_val(env, env.map_get(obj, self.key)).unwrap())
+        } else {
+            None
+        }
+    }
+
+    fn put(self, v: V) {
+        let env = self.map.env().clone();
+        let map = env.map_put(self.map.0.to_object(), self.key, v.into_val(&env));
+        self.map.0 = map.in_env(&env);
+    }
+}
+
 impl<K, V> IntoIterator for Map<K, V>
 where
     K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -429,10 +758,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let env = &self.0 .0.env;
-        let key = env.map_min_key(self.0 .0.to_object());
-        if Status::try_from(key).is_ok() {
-            return None;
-        }
+        let key = min_key(env, self.0 .0.to_object())?;
         let value = env.map_get(self.0 .0.to_object(), key);
         self.0 .0.val = env.map_del(self.0 .0.to_object(), key);
         Some(Ok((
@@ -462,10 +788,7 @@ where
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         let env = &self.0 .0.env;
-        let key = env.map_max_key(self.0 .0.to_object());
-        if Status::try_from(key).is_ok() {
-            return None;
-        }
+        let key = max_key(env, self.0 .0.to_object())?;
         let value = env.map_get(self.0 .0.to_object(), key);
         self.0 .0.val = env.map_del(self.0 .0.to_object(), key);
         Some(Ok((
@@ -500,6 +823,142 @@ where
     }
 }
 
+// The status the host returns in place of a key when there is no key.
+const NO_KEY: Status = Status::UNKNOWN_ERROR;
+
+/// Returns the smallest key in the map, or [None] if the map is empty.
+#[inline(always)]
+fn min_key(env: &Env, obj: Object) -> Option<RawVal> {
+    key_or_none(env, obj, env.map_min_key(obj), None)
+}
+
+/// Returns the largest key in the map, or [None] if the map is empty.
+#[inline(always)]
+fn max_key(env: &Env, obj: Object) -> Option<RawVal> {
+    key_or_none(env, obj, env.map_max_key(obj), None)
+}
+
+/// Returns the smallest key in the map greater than the key, or [None].
+#[inline(always)]
+fn next_key(env: &Env, obj: Object, k: RawVal) -> Option<RawVal> {
+    key_or_none(
+        env,
+        obj,
+        env.map_next_key(obj, k),
+        Some((k, Ordering::Greater)),
+    )
+}
+
+/// Returns the largest key in the map less than the key, or [None].
+#[inline(always)]
+fn prev_key(env: &Env, obj: Object, k: RawVal) -> Option<RawVal> {
+    key_or_none(
+        env,
+        obj,
+        env.map_prev_key(obj, k),
+        Some((k, Ordering::Less)),
+    )
+}
+
+/// Returns the key returned by the host, or [None] if it is the status the
+/// host returns when there is no key.
+///
+/// The status can also be a key of the map, and so it is only treated as no
+/// key if the map does not contain it, or if it is not on the side of the key
+/// that was searched from.
+fn key_or_none(
+    env: &Env,
+    obj: Object,
+    k: RawVal,
+    from: Option<(RawVal, Ordering)>,
+) -> Option<RawVal> {
+    if k.get_payload() != NO_KEY.to_raw().get_payload() {
+        return Some(k);
+    }
+    let is_key = env.map_has(obj, k).is_true()
+        && from.map_or(true, |(from, side)| cmp_keys(env, k, from) == side);
+    if is_key {
+        Some(k)
+    } else {
+        None
+    }
+}
+
+#[inline(always)]
+fn cmp_keys(env: &Env, a: RawVal, b: RawVal) -> Ordering {
+    let v = env.obj_cmp(a, b);
+    let i = i32::try_from(v).unwrap();
+    i.cmp(&0)
+}
+
+/// MapRange is an iterator over the entries of a [Map] with keys within a
+/// range, returned by [Map::range].
+#[derive(Clone)]
+pub struct MapRange<K, V> {
+    map: Map<K, V>,
+    // The first and last keys in the range that have not been visited, or
+    // None once all keys in the range have been visited.
+    bounds: Option<(RawVal, RawVal)>,
+}
+
+impl<K, V> MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn entry(&self, key: RawVal) -> Result<(K, V), ConversionError> {
+        let env = self.map.env();
+        let value = env.map_get(self.map.0.to_object(), key);
+        Ok((
+            K::try_from_val(env, key).map_err(|_| ConversionError)?,
+            V::try_from_val(env, value).map_err(|_| ConversionError)?,
+        ))
+    }
+}
+
+impl<K, V> Iterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    type Item = Result<(K, V), ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.bounds?;
+        let env = self.map.env();
+        self.bounds = if cmp_keys(env, front, back) == Ordering::Equal {
+            None
+        } else {
+            next_key(env, self.map.0.to_object(), front).map(|f| (f, back))
+        };
+        Some(self.entry(front))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.bounds?;
+        let env = self.map.env();
+        self.bounds = if cmp_keys(env, front, back) == Ordering::Equal {
+            None
+        } else {
+            prev_key(env, self.map.0.to_object(), back).map(|b| (front, b))
+        };
+        Some(self.entry(back))
+    }
+}
+
+impl<K, V> FusedIterator for MapRange<K, V>
+where
+    K: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -595,4 +1054,159 @@ mod test {
         assert_eq!(iter.next_back(), None);
         assert_eq!(iter.next_back(), None);
     }
+
+    #[test]
+    fn test_min_max_key() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env];
+        assert_eq!(map.min_key(), None);
+        assert_eq!(map.max_key(), None);
+
+        let map = map![&env, (3, 30), (1, 10), (5, 50)];
+        assert_eq!(map.min_key(), Some(Ok(1)));
+        assert_eq!(map.max_key(), Some(Ok(5)));
+    }
+
+    #[test]
+    fn test_next_prev_key() {
+        let env = Env::default();
+
+        let map = map![&env, (1, 10), (3, 30), (5, 50)];
+        assert_eq!(map.next_key(0), Some(Ok(1)));
+        assert_eq!(map.next_key(1), Some(Ok(3)));
+        assert_eq!(map.next_key(2), Some(Ok(3)));
+        assert_eq!(map.next_key(5), None);
+        assert_eq!(map.prev_key(6), Some(Ok(5)));
+        assert_eq!(map.prev_key(5), Some(Ok(3)));
+        assert_eq!(map.prev_key(4), Some(Ok(3)));
+        assert_eq!(map.prev_key(1), None);
+    }
+
+    #[test]
+    fn test_range() {
+        let env = Env::default();
+
+        let map = map![&env, (1, 10), (3, 30), (5, 50), (7, 70)];
+        let keys =
+            |r: MapRange<u32, u32>| -> std::vec::Vec<u32> { r.map(|e| e.unwrap().0).collect() };
+
+        assert_eq!(keys(map.range(..)), [1, 3, 5, 7]);
+        assert_eq!(keys(map.range(3..)), [3, 5, 7]);
+        assert_eq!(keys(map.range(4..)), [5, 7]);
+        assert_eq!(keys(map.range(..5)), [1, 3]);
+        assert_eq!(keys(map.range(..=5)), [1, 3, 5]);
+        assert_eq!(keys(map.range(2..=6)), [3, 5]);
+        assert_eq!(
+            keys(map.range((Bound::Excluded(3), Bound::Excluded(7)))),
+            [5]
+        );
+        assert!(keys(map.range(4..5)).is_empty());
+        assert!(keys(map.range(8..)).is_empty());
+        assert!(keys(map.range(3..3)).is_empty());
+        assert_eq!(keys(map.range(3..=3)), [3]);
+
+        let mut range = map.range(2..);
+        assert_eq!(range.next_back(), Some(Ok((7, 70))));
+        assert_eq!(range.next(), Some(Ok((3, 30))));
+        assert_eq!(range.next_back(), Some(Ok((5, 50))));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    fn test_entry() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10)];
+        assert!(map.entry(1).is_occupied());
+        assert!(!map.entry(2).is_occupied());
+        assert_eq!(map.entry(2).key(), 2);
+
+        assert_eq!(map.entry(1).or_insert(0), 10);
+        assert_eq!(map.entry(2).or_insert(20), 20);
+        assert_eq!(map.entry(3).or_insert_with(|| 30), 30);
+        assert_eq!(map.entry(4).or_default(), 0);
+        assert_eq!(map.entry(4).and_modify(|v| *v += 40).or_default(), 40);
+        assert_eq!(map.entry(5).and_modify(|v| *v += 50).or_default(), 0);
+        assert_eq!(map, map![&env, (1, 10), (2, 20), (3, 30), (4, 40), (5, 0)]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10), (2, 20)];
+        map.extend([(2, 21), (3, 30)]);
+        assert_eq!(map, map![&env, (1, 10), (2, 21), (3, 30)]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        use crate::iter::CollectIn;
+
+        let env = Env::default();
+
+        let map = Map::from_iter(&env, [(2, 20), (1, 10), (2, 21)]);
+        assert_eq!(map, map![&env, (1, 10), (2, 21)]);
+
+        let map: Map<u32, u32> = map
+            .iter_unchecked()
+            .map(|(k, v)| (k + 1, v + 1))
+            .collect_in(&env);
+        assert_eq!(map, map![&env, (2, 11), (3, 22)]);
+    }
+
+    #[test]
+    fn test_retain_filter() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10), (2, 20), (3, 30), (4, 40)];
+        let even = map.filter(|k, _| k % 2 == 0);
+        assert_eq!(even, map![&env, (2, 20), (4, 40)]);
+        assert_eq!(map.len(), 4);
+
+        map.retain(|_, v| *v > 20);
+        assert_eq!(map, map![&env, (3, 30), (4, 40)]);
+    }
+
+    #[test]
+    fn test_status_keys() {
+        use crate::xdr::ScStatusType;
+
+        let env = Env::default();
+        let error = Status::from_type_and_code(ScStatusType::ContractError, 1);
+        let mut map = Map::<Status, u32>::new(&env);
+        assert_eq!(map.min_key(), None);
+        map.set(NO_KEY, 1);
+        map.set(error, 2);
+
+        let keys: std::vec::Vec<Status> = map.keys().iter().map(Result::unwrap).collect();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(map.iter().count(), 2);
+        assert_eq!(map.iter().rev().count(), 2);
+        assert_eq!(map.range(..).count(), 2);
+        assert_eq!(map.min_key(), Some(Ok(keys[0])));
+        assert_eq!(map.max_key(), Some(Ok(keys[1])));
+        assert_eq!(map.next_key(keys[0]), Some(Ok(keys[1])));
+        assert_eq!(map.next_key(keys[1]), None);
+        assert_eq!(map.prev_key(keys[1]), Some(Ok(keys[0])));
+        assert_eq!(map.prev_key(keys[0]), None);
+
+        let mut merged = Map::<Status, u32>::new(&env);
+        merged.merge(&map);
+        assert_eq!(merged, map);
+    }
+
+    #[test]
+    fn test_merge() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10), (2, 20)];
+        map.merge(&map![&env, (2, 21), (3, 30)]);
+        assert_eq!(map, map![&env, (1, 10), (2, 21), (3, 30)]);
+
+        map.merge(&Map::new(&env));
+        assert_eq!(map, map![&env, (1, 10), (2, 21), (3, 30)]);
+    }
 }
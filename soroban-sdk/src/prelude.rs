@@ -0,0 +1,18 @@
+//! Traits that are commonly needed by contracts, for glob import.
+//!
+//! The prelude contains the extension traits that add functions to types
+//! defined outside the SDK, such as [SymbolExt] for [Symbol](crate::Symbol),
+//! so that those functions can be called without importing each trait.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::prelude::*;
+//! use soroban_sdk::{symbol, Bytes, Env, Symbol};
+//!
+//! let env = Env::default();
+//! let b = Bytes::from_slice(&env, b"hello");
+//! assert_eq!(Symbol::try_from_bytes(&b), Ok(symbol!("hello")));
+//! ```
+
+pub use crate::symbol::SymbolExt;
@@ -0,0 +1,488 @@
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{Bound, RangeBounds},
+};
+
+use super::{
+    bytes::CHUNK_LEN,
+    env::{EnvObj, EnvType, IntoVal},
+    Bytes, ConversionError, Env, EnvVal, Object, RawVal, TryIntoVal,
+};
+
+#[cfg(not(target_family = "wasm"))]
+use super::xdr::ScVal;
+
+/// String is a UTF-8 encoded string.
+///
+/// The string is stored in the Host as a binary object, the same as [Bytes],
+/// and is checked to be valid UTF-8 when it is created from a value that is
+/// not already known to be a String, such as a [RawVal] passed to a
+/// contract.
+///
+/// Unlike [Symbol](crate::Symbol), strings may be of any length and contain
+/// any unicode characters.
+///
+/// Lengths and indexes of strings are in bytes, not characters, the same as
+/// [str].
+///
+/// Contract specs describe strings as binary, because there is not yet a
+/// string type in the spec.
+///
+/// Importing everything from the SDK with `use soroban_sdk::*` imports this
+/// type, which shadows `std::string::String` in code that uses the standard
+/// library, such as tests. Refer to the standard library type by its full path
+/// in that code, or import the SDK's types individually.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Env, String};
+///
+/// let env = Env::default();
+/// let mut s = String::from_str(&env, "hello");
+/// s.push_str(", wörld");
+/// assert_eq!(s.len(), 13);
+/// assert_eq!(s, String::from_str(&env, "hello, wörld"));
+/// assert_eq!(s.slice(7..), String::from_str(&env, "wörld"));
+/// ```
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct String(Bytes);
+
+impl Debug for String {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "String(\"")?;
+        for_each_str_chunk(&self.0, core::fmt::Error, |s| {
+            write!(f, "{}", s.escape_debug())
+        })?;
+        write!(f, "\")")
+    }
+}
+
+/// Strings that fit in a single chunk copied out of the host are padded and
+/// truncated according to the width, fill, alignment and precision flags.
+/// Longer strings are written in chunks and the flags are ignored.
+impl Display for String {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.len() as usize <= CHUNK_LEN {
+            let mut buf = [0u8; CHUNK_LEN];
+            f.pad(self.copy_into_str(&mut buf))
+        } else {
+            for_each_str_chunk(&self.0, core::fmt::Error, |s| f.write_str(s))
+        }
+    }
+}
+
+impl Eq for String {}
+
+impl PartialEq for String {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for String {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl Ord for String {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The byte order of UTF-8 is the order of the characters encoded.
+        self.0.cmp(&other.0)
+    }
+}
+
+impl TryFrom<EnvVal> for String {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(ev: EnvVal) -> Result<Self, Self::Error> {
+        let bin: Bytes = ev.try_into()?;
+        bin.try_into()
+    }
+}
+
+impl TryFrom<EnvObj> for String {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(obj: EnvObj) -> Result<Self, Self::Error> {
+        let bin: Bytes = obj.try_into()?;
+        bin.try_into()
+    }
+}
+
+impl TryFrom<Bytes> for String {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(bin: Bytes) -> Result<Self, Self::Error> {
+        String::from_bytes(bin)
+    }
+}
+
+impl TryIntoVal<Env, String> for RawVal {
+    type Error = ConversionError;
+
+    fn try_into_val(self, env: &Env) -> Result<String, Self::Error> {
+        EnvType {
+            env: env.clone(),
+            val: self,
+        }
+        .try_into()
+    }
+}
+
+impl From<String> for RawVal {
+    #[inline(always)]
+    fn from(v: String) -> Self {
+        v.0.into()
+    }
+}
+
+impl From<String> for EnvVal {
+    #[inline(always)]
+    fn from(v: String) -> Self {
+        v.0.into()
+    }
+}
+
+impl From<String> for EnvObj {
+    #[inline(always)]
+    fn from(v: String) -> Self {
+        v.0.into()
+    }
+}
+
+impl From<String> for Bytes {
+    #[inline(always)]
+    fn from(v: String) -> Self {
+        v.0
+    }
+}
+
+impl From<&String> for Object {
+    #[inline(always)]
+    fn from(v: &String) -> Self {
+        v.0.to_object()
+    }
+}
+
+impl AsRef<Bytes> for String {
+    fn as_ref(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<&String> for ScVal {
+    type Error = ConversionError;
+    fn try_from(v: &String) -> Result<Self, Self::Error> {
+        (&v.0).try_into()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<String> for ScVal {
+    type Error = ConversionError;
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        (&v).try_into()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryIntoVal<Env, String> for ScVal {
+    type Error = ConversionError;
+    fn try_into_val(self, env: &Env) -> Result<String, Self::Error> {
+        let bin: Bytes = self.try_into_val(env)?;
+        bin.try_into()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TryFrom<EnvType<ScVal>> for String {
+    type Error = ConversionError;
+    fn try_from(v: EnvType<ScVal>) -> Result<Self, Self::Error> {
+        ScVal::try_into_val(v.val, &v.env)
+    }
+}
+
+impl IntoVal<Env, String> for &str {
+    fn into_val(self, env: &Env) -> String {
+        String::from_str(env, self)
+    }
+}
+
+impl From<EnvType<&str>> for String {
+    fn from(ev: EnvType<&str>) -> Self {
+        String::from_str(&ev.env, ev.val)
+    }
+}
+
+impl String {
+    /// Create an empty String.
+    #[inline(always)]
+    pub fn new(env: &Env) -> String {
+        String(Bytes::new(env))
+    }
+
+    /// Create a String from the given `&str`.
+    #[inline(always)]
+    pub fn from_str(env: &Env, s: &str) -> String {
+        String(Bytes::from_slice(env, s.as_bytes()))
+    }
+
+    /// Create a String from the bytes, checking that the bytes are valid
+    /// UTF-8.
+    ///
+    /// Returns an error if the bytes are not valid UTF-8.
+    pub fn from_bytes(bin: Bytes) -> Result<String, ConversionError> {
+        for_each_str_chunk(&bin, ConversionError, |_| Ok(()))?;
+        Ok(String(bin))
+    }
+
+    /// Returns the UTF-8 encoded bytes of the string.
+    #[inline(always)]
+    pub fn to_bytes(&self) -> Bytes {
+        self.0.clone()
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> u32 {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if the index is the start of a character in the string,
+    /// or is the length of the string.
+    pub fn is_char_boundary(&self, i: u32) -> bool {
+        match self.0.get(i) {
+            // Continuation bytes of a character all begin with 0b10.
+            Some(b) => b & 0xc0 != 0x80,
+            None => i == self.len(),
+        }
+    }
+
+    /// Appends the string to the end of the string.
+    #[inline(always)]
+    pub fn append(&mut self, other: &String) {
+        self.0.append(&other.0);
+    }
+
+    /// Appends the `&str` to the end of the string.
+    #[inline(always)]
+    pub fn push_str(&mut self, s: &str) {
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    /// Returns a new string that is the string followed by the other string.
+    #[must_use]
+    pub fn concat(&self, other: &String) -> String {
+        let mut s = self.clone();
+        s.append(other);
+        s
+    }
+
+    /// Returns the part of the string within the range of byte indexes, or
+    /// [None] if the range is out of bounds or does not begin and end on
+    /// character boundaries.
+    #[must_use]
+    pub fn get(&self, r: impl RangeBounds<u32>) -> Option<String> {
+        let start = match r.start_bound() {
+            Bound::Included(s) => *s,
+            Bound::Excluded(s) => s.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match r.end_bound() {
+            Bound::Included(s) => s.checked_add(1)?,
+            Bound::Excluded(s) => *s,
+            Bound::Unbounded => self.len(),
+        };
+        if start > end
+            || end > self.len()
+            || !self.is_char_boundary(start)
+            || !self.is_char_boundary(end)
+        {
+            return None;
+        }
+        Some(String(self.0.slice(start..end)))
+    }
+
+    /// Returns the part of the string within the range of byte indexes.
+    ///
+    /// ### Panics
+    ///
+    /// If the range is out of bounds or does not begin and end on character
+    /// boundaries.
+    #[must_use]
+    pub fn slice(&self, r: impl RangeBounds<u32>) -> String {
+        self.get(r).expect("range is not on character boundaries")
+    }
+
+    /// Copy the string into the buffer, returning the `&str` in the buffer.
+    ///
+    /// ### Panics
+    ///
+    /// If the buffer is shorter than the string.
+    pub fn copy_into_str<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        let buf = &mut buf[..self.len() as usize];
+        self.0.copy_into_slice(buf);
+        // The string is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(buf) }
+    }
+}
+
+/// Calls the function with consecutive `&str`s that make up the bytes, each
+/// built from the chunks the bytes are copied out of the host in, and returns
+/// the invalid error if the bytes are not valid UTF-8.
+fn for_each_str_chunk<E>(
+    bin: &Bytes,
+    invalid: E,
+    mut f: impl FnMut(&str) -> Result<(), E>,
+) -> Result<(), E> {
+    // Characters are at most four bytes, and so at most three bytes of an
+    // incomplete character are carried between chunks.
+    let mut buf = [0u8; CHUNK_LEN + 3];
+    let mut carry = 0;
+    let res = bin.for_each_chunk(|chunk| {
+        let total = carry + chunk.len();
+        buf[carry..total].copy_from_slice(chunk);
+        match core::str::from_utf8(&buf[..total]) {
+            Ok(s) => {
+                carry = 0;
+                f(s).map_err(Some)
+            }
+            Err(e) if e.error_len().is_none() => {
+                let valid = e.valid_up_to();
+                f(unsafe { core::str::from_utf8_unchecked(&buf[..valid]) }).map_err(Some)?;
+                buf.copy_within(valid..total, 0);
+                carry = total - valid;
+                Ok(())
+            }
+            Err(_) => Err(None),
+        }
+    });
+    match res {
+        Ok(()) if carry == 0 => Ok(()),
+        Ok(()) | Err(None) => Err(invalid),
+        Err(Some(e)) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let env = Env::default();
+
+        let s = String::from_bytes(Bytes::from_slice(&env, "héllo".as_bytes())).unwrap();
+        assert_eq!(s, String::from_str(&env, "héllo"));
+        assert_eq!(s.len(), 6);
+
+        assert_eq!(
+            String::from_bytes(Bytes::from_slice(&env, &[0x68, 0xff])),
+            Err(ConversionError)
+        );
+        // An incomplete character at the end.
+        assert_eq!(
+            String::from_bytes(Bytes::from_slice(&env, &[0x68, 0xc3])),
+            Err(ConversionError)
+        );
+    }
+
+    #[test]
+    fn test_long_strings() {
+        let env = Env::default();
+
+        // Multi-byte characters that cross the boundaries of chunks.
+        let mut expected = std::string::String::new();
+        let mut s = String::new(&env);
+        for _ in 0..50 {
+            expected.push_str("aé€😀");
+            s.push_str("aé€😀");
+        }
+        let s = String::from_bytes(s.to_bytes()).unwrap();
+        assert_eq!(format!("{}", s), expected);
+
+        let mut bin = s.to_bytes();
+        bin.pop();
+        assert!(String::from_bytes(bin).is_err());
+    }
+
+    #[test]
+    fn test_slice() {
+        let env = Env::default();
+
+        let s = String::from_str(&env, "aé€");
+        assert!(s.is_char_boundary(0));
+        assert!(s.is_char_boundary(1));
+        assert!(!s.is_char_boundary(2));
+        assert!(s.is_char_boundary(6));
+        assert!(!s.is_char_boundary(7));
+
+        assert_eq!(s.get(1..3), Some(String::from_str(&env, "é")));
+        assert_eq!(s.get(3..), Some(String::from_str(&env, "€")));
+        assert_eq!(s.get(..=2), Some(String::from_str(&env, "aé")));
+        assert_eq!(s.get(2..), None);
+        assert_eq!(s.get(..7), None);
+        assert_eq!(s.slice(..1), String::from_str(&env, "a"));
+    }
+
+    #[test]
+    #[should_panic(expected = "range is not on character boundaries")]
+    fn test_slice_not_on_boundary() {
+        let env = Env::default();
+        let _ = String::from_str(&env, "é").slice(1..);
+    }
+
+    #[test]
+    fn test_concat_and_fmt() {
+        let env = Env::default();
+
+        let a = String::from_str(&env, "hello ");
+        let b = String::from_str(&env, "\"wörld\"");
+        let s = a.concat(&b);
+        assert_eq!(format!("{}", s), "hello \"wörld\"");
+        assert_eq!(format!("{:?}", s), "String(\"hello \\\"wörld\\\"\")");
+        assert_eq!(format!("{:>16}", s), "   hello \"wörld\"");
+        assert_eq!(format!("{:*<8.5}", s), "hello***");
+
+        let mut buf = [0u8; 32];
+        assert_eq!(s.copy_into_str(&mut buf), "hello \"wörld\"");
+    }
+
+    #[test]
+    fn test_raw_val() {
+        let env = Env::default();
+
+        let s = String::from_str(&env, "hello");
+        let val: RawVal = s.clone().into();
+        let rt: String = val.try_into_val(&env).unwrap();
+        assert_eq!(s, rt);
+
+        let val: RawVal = Bytes::from_slice(&env, &[0xff]).into();
+        let res: Result<String, _> = val.try_into_val(&env);
+        assert!(res.is_err());
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_scval() {
+        let env = Env::default();
+
+        let s = String::from_str(&env, "hello");
+        let val: ScVal = s.clone().try_into().unwrap();
+        let rt: String = val.try_into_val(&env).unwrap();
+        assert_eq!(s, rt);
+    }
+}
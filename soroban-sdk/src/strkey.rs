@@ -0,0 +1,268 @@
+//! Strkey encoding and decoding of account and contract identifiers.
+//!
+//! Strkeys are the base32 encoding of a version byte, the 32 bytes of the
+//! identifier, and a CRC16 checksum. Account strkeys begin with `G` and
+//! contract strkeys begin with `C`.
+//!
+//! Decoding is available as `const fn` so that identifiers can be embedded
+//! in contracts as constants and validated at compile time.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::strkey;
+//!
+//! const ADMIN: [u8; 32] =
+//!     match strkey::decode_account("GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ") {
+//!         Ok(pk) => pk,
+//!         Err(_) => panic!("invalid strkey"),
+//!     };
+//!
+//! let s = strkey::encode_account(&ADMIN);
+//! assert_eq!(s.as_str(), "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ");
+//! ```
+
+use core::fmt::{Debug, Display};
+
+const VERSION_ACCOUNT: u8 = 6 << 3;
+const VERSION_CONTRACT: u8 = 2 << 3;
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The length of an encoded strkey for a 32-byte identifier.
+pub const STRKEY_LEN: usize = 56;
+
+const RAW_LEN: usize = 35;
+
+/// Error returned when a strkey cannot be decoded.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Error {
+    /// The strkey is not the length of a strkey for a 32-byte identifier.
+    InvalidLength,
+    /// The strkey contains a character outside of the base32 alphabet.
+    InvalidCharacter,
+    /// The strkey is not of the expected kind, e.g. a contract strkey was
+    /// decoded as an account.
+    InvalidVersion,
+    /// The checksum of the strkey does not match its contents.
+    InvalidChecksum,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::InvalidLength => write!(f, "strkey has invalid length"),
+            Error::InvalidCharacter => write!(f, "strkey has invalid character"),
+            Error::InvalidVersion => write!(f, "strkey has invalid version"),
+            Error::InvalidChecksum => write!(f, "strkey has invalid checksum"),
+        }
+    }
+}
+
+/// StrKey is an encoded strkey.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct StrKey([u8; STRKEY_LEN]);
+
+impl StrKey {
+    /// Returns the strkey as a string.
+    pub fn as_str(&self) -> &str {
+        // The encoded strkey only contains characters of the base32 alphabet.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl Display for StrKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Debug for StrKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "StrKey({})", self.as_str())
+    }
+}
+
+impl AsRef<str> for StrKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Decode an account strkey (`G...`) into the 32-byte public key.
+pub const fn decode_account(s: &str) -> Result<[u8; 32], Error> {
+    decode(VERSION_ACCOUNT, s)
+}
+
+/// Decode a contract strkey (`C...`) into the 32-byte contract id.
+pub const fn decode_contract(s: &str) -> Result<[u8; 32], Error> {
+    decode(VERSION_CONTRACT, s)
+}
+
+/// Encode the 32-byte public key as an account strkey (`G...`).
+pub const fn encode_account(pk: &[u8; 32]) -> StrKey {
+    StrKey(encode(VERSION_ACCOUNT, pk))
+}
+
+/// Encode the 32-byte contract id as a contract strkey (`C...`).
+pub const fn encode_contract(id: &[u8; 32]) -> StrKey {
+    StrKey(encode(VERSION_CONTRACT, id))
+}
+
+const fn crc16(data: &[u8; RAW_LEN], len: usize) -> u16 {
+    let mut crc: u16 = 0;
+    let mut i = 0;
+    while i < len {
+        crc ^= (data[i] as u16) << 8;
+        let mut j = 0;
+        while j < 8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+const fn decode(version: u8, s: &str) -> Result<[u8; 32], Error> {
+    let s = s.as_bytes();
+    if s.len() != STRKEY_LEN {
+        return Err(Error::InvalidLength);
+    }
+
+    let mut raw = [0u8; RAW_LEN];
+    let mut bits: u32 = 0;
+    let mut nbits: u32 = 0;
+    let mut n = 0;
+    let mut i = 0;
+    while i < STRKEY_LEN {
+        let v = match s[i] {
+            b'A'..=b'Z' => s[i] - b'A',
+            b'2'..=b'7' => s[i] - b'2' + 26,
+            _ => return Err(Error::InvalidCharacter),
+        };
+        bits = (bits << 5) | v as u32;
+        nbits += 5;
+        if nbits >= 8 {
+            nbits -= 8;
+            raw[n] = (bits >> nbits) as u8;
+            bits &= (1 << nbits) - 1;
+            n += 1;
+        }
+        i += 1;
+    }
+
+    if raw[0] != version {
+        return Err(Error::InvalidVersion);
+    }
+    let crc = crc16(&raw, RAW_LEN - 2);
+    if raw[RAW_LEN - 2] != crc as u8 || raw[RAW_LEN - 1] != (crc >> 8) as u8 {
+        return Err(Error::InvalidChecksum);
+    }
+
+    let mut id = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        id[i] = raw[i + 1];
+        i += 1;
+    }
+    Ok(id)
+}
+
+const fn encode(version: u8, id: &[u8; 32]) -> [u8; STRKEY_LEN] {
+    let mut raw = [0u8; RAW_LEN];
+    raw[0] = version;
+    let mut i = 0;
+    while i < 32 {
+        raw[i + 1] = id[i];
+        i += 1;
+    }
+    let crc = crc16(&raw, RAW_LEN - 2);
+    raw[RAW_LEN - 2] = crc as u8;
+    raw[RAW_LEN - 1] = (crc >> 8) as u8;
+
+    let mut s = [0u8; STRKEY_LEN];
+    let mut bits: u32 = 0;
+    let mut nbits: u32 = 0;
+    let mut n = 0;
+    let mut i = 0;
+    while i < RAW_LEN {
+        bits = (bits << 8) | raw[i] as u32;
+        nbits += 8;
+        while nbits >= 5 {
+            nbits -= 5;
+            s[n] = ALPHABET[((bits >> nbits) & 0x1f) as usize];
+            n += 1;
+        }
+        bits &= (1 << nbits) - 1;
+        i += 1;
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Account, BytesN, Env, Identifier};
+
+    const ACCOUNT: &str = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    const ACCOUNT_PK: [u8; 32] = [
+        0x3f, 0x0c, 0x34, 0xbf, 0x93, 0xad, 0x0d, 0x99, 0x71, 0xd0, 0x4c, 0xcc, 0x90, 0xf7, 0x05,
+        0x51, 0x1c, 0x83, 0x8a, 0xad, 0x97, 0x34, 0xa4, 0xa2, 0xfb, 0x0d, 0x7a, 0x03, 0xfc, 0x7f,
+        0xe8, 0x9a,
+    ];
+    const CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+    const CONTRACT_ID: [u8; 32] = [
+        0x36, 0x3e, 0xaa, 0x38, 0x67, 0x84, 0x1f, 0xba, 0xd0, 0xf4, 0xed, 0x88, 0xc7, 0x79, 0xe4,
+        0xfe, 0x66, 0xe5, 0x6a, 0x24, 0x70, 0xdc, 0x98, 0xc0, 0xec, 0x9c, 0x07, 0x3d, 0x05, 0xc7,
+        0xb1, 0x03,
+    ];
+
+    #[test]
+    fn test_account() {
+        assert_eq!(decode_account(ACCOUNT), Ok(ACCOUNT_PK));
+        assert_eq!(encode_account(&ACCOUNT_PK).as_str(), ACCOUNT);
+    }
+
+    #[test]
+    fn test_contract() {
+        assert_eq!(decode_contract(CONTRACT), Ok(CONTRACT_ID));
+        assert_eq!(encode_contract(&CONTRACT_ID).as_str(), CONTRACT);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(decode_contract(ACCOUNT), Err(Error::InvalidVersion));
+        assert_eq!(decode_account(&ACCOUNT[1..]), Err(Error::InvalidLength));
+        assert_eq!(
+            decode_account("GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSG1"),
+            Err(Error::InvalidCharacter)
+        );
+        assert_eq!(
+            decode_account("GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGA"),
+            Err(Error::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn test_types() {
+        let env = Env::default();
+        let pk = BytesN::from_account_strkey(&env, ACCOUNT).unwrap();
+        assert_eq!(pk, BytesN::from_array(&env, ACCOUNT_PK));
+        assert_eq!(pk.to_account_strkey().as_str(), ACCOUNT);
+
+        let a = Account::from_strkey(&env, ACCOUNT).unwrap();
+        assert_eq!(format!("{}", a), ACCOUNT);
+
+        let id = BytesN::from_contract_strkey(&env, CONTRACT).unwrap();
+        assert_eq!(format!("{}", Identifier::Contract(id)), CONTRACT);
+        assert_eq!(
+            BytesN::from_contract_strkey(&env, ACCOUNT),
+            Err(Error::InvalidVersion)
+        );
+    }
+}
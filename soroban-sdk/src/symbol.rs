@@ -0,0 +1,164 @@
+use super::{Bytes, ConversionError, Env, Symbol};
+
+/// The maximum number of characters in a [Symbol].
+pub const SYMBOL_MAX_LEN: usize = 10;
+
+/// SymbolExt provides conversions between [Symbol] and [Bytes], and
+/// concatenation of symbols, checked at runtime.
+///
+/// The functions are provided by a trait because [Symbol] is defined in
+/// `soroban-env-common` and functions cannot be added to it directly. The
+/// trait is included in the [prelude](crate::prelude), so that the functions
+/// are available on [Symbol] with `use soroban_sdk::prelude::*`.
+///
+/// Symbols are limited to [SYMBOL_MAX_LEN] characters from the set
+/// `[_0-9A-Za-z]`. Use the [symbol!](crate::symbol) macro to create a symbol
+/// from a literal that is checked at compile time instead.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::prelude::*;
+/// use soroban_sdk::{symbol, Bytes, Env, Symbol};
+///
+/// let env = Env::default();
+/// let b = Bytes::from_slice(&env, b"hello");
+/// let s = Symbol::try_from_bytes(&b).unwrap();
+/// assert_eq!(s, symbol!("hello"));
+/// assert_eq!(s.to_bytes(&env), b);
+/// assert_eq!(s.concat(&symbol!("_wld")), Ok(symbol!("hello_wld")));
+/// assert!(s.concat(&symbol!("_world")).is_err());
+/// ```
+pub trait SymbolExt: Sized {
+    /// Create a symbol from the bytes of its characters.
+    ///
+    /// ### Errors
+    ///
+    /// If the bytes are longer than [SYMBOL_MAX_LEN] or contain a character
+    /// that is not valid in a symbol.
+    fn try_from_bytes(b: &Bytes) -> Result<Self, ConversionError>;
+
+    /// Returns the characters of the symbol as bytes.
+    fn to_bytes(&self, env: &Env) -> Bytes;
+
+    /// Returns a symbol of the characters of this symbol followed by the
+    /// characters of the other symbol.
+    ///
+    /// ### Errors
+    ///
+    /// If the combined length is longer than [SYMBOL_MAX_LEN].
+    fn concat(&self, other: &Self) -> Result<Self, ConversionError>;
+}
+
+impl SymbolExt for Symbol {
+    fn try_from_bytes(b: &Bytes) -> Result<Self, ConversionError> {
+        let len = b.len() as usize;
+        if len > SYMBOL_MAX_LEN {
+            return Err(ConversionError);
+        }
+        let mut buf = [0u8; SYMBOL_MAX_LEN];
+        b.copy_into_slice(&mut buf[..len]);
+        symbol_from_slice(&buf[..len])
+    }
+
+    fn to_bytes(&self, env: &Env) -> Bytes {
+        let s = self.to_str();
+        let s: &str = s.as_ref();
+        Bytes::from_slice(env, s.as_bytes())
+    }
+
+    fn concat(&self, other: &Self) -> Result<Self, ConversionError> {
+        let a = self.to_str();
+        let a: &str = a.as_ref();
+        let b = other.to_str();
+        let b: &str = b.as_ref();
+        let len = a.len() + b.len();
+        if len > SYMBOL_MAX_LEN {
+            return Err(ConversionError);
+        }
+        let mut buf = [0u8; SYMBOL_MAX_LEN];
+        buf[..a.len()].copy_from_slice(a.as_bytes());
+        buf[a.len()..len].copy_from_slice(b.as_bytes());
+        symbol_from_slice(&buf[..len])
+    }
+}
+
+impl TryFrom<&Bytes> for Symbol {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(b: &Bytes) -> Result<Self, Self::Error> {
+        Symbol::try_from_bytes(b)
+    }
+}
+
+impl TryFrom<Bytes> for Symbol {
+    type Error = ConversionError;
+
+    #[inline(always)]
+    fn try_from(b: Bytes) -> Result<Self, Self::Error> {
+        Symbol::try_from_bytes(&b)
+    }
+}
+
+fn symbol_from_slice(b: &[u8]) -> Result<Symbol, ConversionError> {
+    let s = core::str::from_utf8(b).map_err(|_| ConversionError)?;
+    Symbol::try_from_str(s).map_err(|_| ConversionError)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_from_bytes() {
+        let env = Env::default();
+
+        let b = Bytes::from_slice(&env, b"");
+        assert_eq!(Symbol::try_from_bytes(&b), Ok(Symbol::from_str("")));
+
+        let b = Bytes::from_slice(&env, b"Abc_019xyZ");
+        assert_eq!(
+            Symbol::try_from_bytes(&b),
+            Ok(Symbol::from_str("Abc_019xyZ"))
+        );
+        assert_eq!(Symbol::try_from(b), Ok(Symbol::from_str("Abc_019xyZ")));
+
+        let b = Bytes::from_slice(&env, b"abcdefghijk");
+        assert_eq!(Symbol::try_from_bytes(&b), Err(ConversionError));
+
+        let b = Bytes::from_slice(&env, b"ab-c");
+        assert_eq!(Symbol::try_from_bytes(&b), Err(ConversionError));
+
+        let b = Bytes::from_slice(&env, &[0xff]);
+        assert_eq!(Symbol::try_from_bytes(&b), Err(ConversionError));
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let env = Env::default();
+
+        let s = Symbol::from_str("");
+        assert_eq!(s.to_bytes(&env), Bytes::new(&env));
+
+        let s = Symbol::from_str("hello_123");
+        assert_eq!(s.to_bytes(&env), Bytes::from_slice(&env, b"hello_123"));
+        assert_eq!(Symbol::try_from_bytes(&s.to_bytes(&env)), Ok(s));
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = Symbol::from_str("hello");
+        let b = Symbol::from_str("_world");
+        let c = Symbol::from_str("_w");
+
+        assert_eq!(a.concat(&c), Ok(Symbol::from_str("hello_w")));
+        assert_eq!(a.concat(&Symbol::from_str("")), Ok(a));
+        assert_eq!(Symbol::from_str("").concat(&a), Ok(a));
+        assert_eq!(
+            Symbol::from_str("hello").concat(&Symbol::from_str("world")),
+            Ok(Symbol::from_str("helloworld"))
+        );
+        assert_eq!(a.concat(&b), Err(ConversionError));
+    }
+}
@@ -4,9 +4,18 @@
 mod test_sign;
 pub use test_sign::ed25519;
 
+pub mod accounts;
+pub mod arbitrary;
+pub mod fuzz;
+
 use crate::{Env, RawVal, Symbol};
 
 #[doc(hidden)]
 pub trait ContractFunctionSet {
     fn call(&self, func: &Symbol, env: Env, args: &[RawVal]) -> Option<RawVal>;
 }
+
+#[doc(hidden)]
+pub trait ContractFunctionSpec {
+    fn spec_xdrs(&self) -> &'static [&'static [u8]];
+}
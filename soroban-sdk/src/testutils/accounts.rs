@@ -0,0 +1,194 @@
+#![cfg(feature = "testutils")]
+
+//! Creation and modification of Stellar accounts in the test ledger.
+//!
+//! Accounts do not exist in the ledger of a default [Env], and so any use of
+//! [Account](crate::Account) in a contract under test needs the accounts it
+//! references to be created first.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{BytesN, Env};
+//!
+//! let env = Env::default();
+//! let pk = BytesN::from_array(&env, [1; 32]);
+//! let signer = BytesN::from_array(&env, [2; 32]);
+//!
+//! env.accounts().create(&pk);
+//! env.accounts().set_thresholds(&pk, 1, 2, 3);
+//! env.accounts().set_signer_weight(&pk, &signer, 2);
+//! ```
+
+use crate::{
+    env::internal::xdr::{
+        AccountEntry, AccountEntryExt, AccountId, LedgerEntry, LedgerEntryData, LedgerEntryExt,
+        LedgerKey, LedgerKeyAccount, PublicKey, SequenceNumber, Signer, SignerKey, Thresholds,
+        Uint256,
+    },
+    BytesN, Env,
+};
+
+/// Accounts provides access to the Stellar accounts in the test ledger of an
+/// [Env].
+pub struct Accounts(Env);
+
+impl Accounts {
+    pub(crate) fn new(env: &Env) -> Accounts {
+        Accounts(env.clone())
+    }
+
+    /// Create an account for the public key, with a master key weight of one
+    /// (`1`), all thresholds zero (`0`) and no other signers.
+    ///
+    /// ### Panics
+    ///
+    /// If the account already exists.
+    pub fn create(&self, pk: &BytesN<32>) {
+        let key = ledger_key(pk);
+        if self.has(&key) {
+            panic!("account already exists");
+        }
+        let entry = LedgerEntry {
+            last_modified_ledger_seq: 0,
+            data: LedgerEntryData::Account(AccountEntry {
+                account_id: account_id(pk),
+                balance: 0,
+                seq_num: SequenceNumber(0),
+                num_sub_entries: 0,
+                inflation_dest: None,
+                flags: 0,
+                home_domain: Default::default(),
+                thresholds: Thresholds([1, 0, 0, 0]),
+                signers: Default::default(),
+                ext: AccountEntryExt::V0,
+            }),
+            ext: LedgerEntryExt::V0,
+        };
+        self.put(&key, &entry);
+    }
+
+    /// Returns true if the account exists in the test ledger.
+    pub fn exists(&self, pk: &BytesN<32>) -> bool {
+        self.has(&ledger_key(pk))
+    }
+
+    /// Remove the account from the test ledger.
+    ///
+    /// ### Panics
+    ///
+    /// If the account does not exist.
+    pub fn remove(&self, pk: &BytesN<32>) {
+        let key = ledger_key(pk);
+        if !self.has(&key) {
+            panic!("account does not exist");
+        }
+        self.0
+            .host()
+            .with_mut_storage(|storage| storage.del(&key))
+            .unwrap();
+    }
+
+    /// Set the low, medium and high thresholds of the account.
+    ///
+    /// ### Panics
+    ///
+    /// If the account does not exist.
+    pub fn set_thresholds(&self, pk: &BytesN<32>, low: u8, medium: u8, high: u8) {
+        self.update(pk, |a| {
+            a.thresholds.0[1] = low;
+            a.thresholds.0[2] = medium;
+            a.thresholds.0[3] = high;
+        });
+    }
+
+    /// Set the weight of the master key of the account, which is the key of
+    /// the account itself.
+    ///
+    /// ### Panics
+    ///
+    /// If the account does not exist.
+    pub fn set_master_weight(&self, pk: &BytesN<32>, weight: u8) {
+        self.update(pk, |a| a.thresholds.0[0] = weight);
+    }
+
+    /// Set the weight of an ed25519 signer of the account, adding the signer
+    /// if it is not already a signer of the account. Setting a weight of zero
+    /// (`0`) removes the signer.
+    ///
+    /// Setting the weight of the account's own key sets the master weight.
+    ///
+    /// ### Panics
+    ///
+    /// If the account does not exist, or if the account has the maximum
+    /// number of signers and the signer is being added.
+    pub fn set_signer_weight(&self, pk: &BytesN<32>, signer: &BytesN<32>, weight: u8) {
+        if pk == signer {
+            self.set_master_weight(pk, weight);
+            return;
+        }
+        let key = SignerKey::Ed25519(Uint256(signer.to_array()));
+        self.update(pk, |a| {
+            let mut signers = a.signers.to_vec();
+            signers.retain(|s| s.key != key);
+            if weight > 0 {
+                signers.push(Signer {
+                    key,
+                    weight: weight.into(),
+                });
+            }
+            a.num_sub_entries = signers.len() as u32;
+            a.signers = signers.try_into().unwrap();
+        });
+    }
+
+    /// Remove an ed25519 signer from the account.
+    ///
+    /// ### Panics
+    ///
+    /// If the account does not exist.
+    pub fn remove_signer(&self, pk: &BytesN<32>, signer: &BytesN<32>) {
+        self.set_signer_weight(pk, signer, 0);
+    }
+
+    fn has(&self, key: &LedgerKey) -> bool {
+        self.0
+            .host()
+            .with_mut_storage(|storage| storage.has(key))
+            .unwrap()
+    }
+
+    fn put(&self, key: &LedgerKey, entry: &LedgerEntry) {
+        self.0
+            .host()
+            .with_mut_storage(|storage| storage.put(key, entry))
+            .unwrap();
+    }
+
+    fn update(&self, pk: &BytesN<32>, f: impl FnOnce(&mut AccountEntry)) {
+        let key = ledger_key(pk);
+        if !self.has(&key) {
+            panic!("account does not exist");
+        }
+        let mut entry = self
+            .0
+            .host()
+            .with_mut_storage(|storage| storage.get(&key))
+            .unwrap();
+        match &mut entry.data {
+            LedgerEntryData::Account(a) => f(a),
+            _ => panic!("ledger entry is not an account"),
+        }
+        self.put(&key, &entry);
+    }
+}
+
+fn account_id(pk: &BytesN<32>) -> AccountId {
+    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(pk.to_array())))
+}
+
+fn ledger_key(pk: &BytesN<32>) -> LedgerKey {
+    LedgerKey::Account(LedgerKeyAccount {
+        account_id: account_id(pk),
+    })
+}
@@ -0,0 +1,219 @@
+//! Arbitrary generation of SDK types for property testing and fuzzing.
+//!
+//! SDK types such as [Vec], [Map], [Bytes] and [BigInt] are stored in the
+//! [Env] and so cannot implement [Arbitrary] directly. Types implementing
+//! [ArbitraryVal] are generated from raw unstructured input given an [Env].
+//!
+//! Types defined with `#[contracttype]` implement [ArbitraryVal] when the
+//! crate that defines them has a `testutils` feature enabled, that enables the
+//! `testutils` feature of the SDK.
+//!
+//! ### Examples
+//!
+//! ```
+//! use soroban_sdk::{Env, Vec};
+//! use soroban_sdk::testutils::arbitrary::{ArbitraryVal, Unstructured, MAX_LEN};
+//!
+//! let env = Env::default();
+//! let mut u = Unstructured::new(&[3, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+//! let v = Vec::<u32>::arbitrary_val(&env, &mut u).unwrap();
+//! assert!(v.len() <= MAX_LEN);
+//! ```
+
+pub use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{
+    Account, BigInt, Bytes, BytesN, Env, Identifier, IntoVal, Map, RawVal, String, Symbol,
+    TryFromVal, Vec, SYMBOL_MAX_LEN,
+};
+
+/// The maximum number of elements generated for a [Vec], [Map] or [Bytes].
+pub const MAX_LEN: u32 = 32;
+
+/// The maximum number of 64-bit limbs generated for a [BigInt].
+pub const MAX_BIGINT_LIMBS: u32 = 4;
+
+const SYMBOL_CHARS: &[u8] = b"_0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// ArbitraryVal is implemented by types that can be generated from
+/// unstructured input given an [Env].
+pub trait ArbitraryVal: Sized {
+    /// Generate an arbitrary value of the type in the [Env].
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self>;
+}
+
+macro_rules! impl_arbitrary_val_for_arbitrary {
+    ($($t:ty),*) => {
+        $(
+            impl ArbitraryVal for $t {
+                fn arbitrary_val(_env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+                    <$t as Arbitrary>::arbitrary(u)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_val_for_arbitrary!((), bool, u32, i32, u64, i64);
+
+impl ArbitraryVal for Symbol {
+    fn arbitrary_val(_env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = u.int_in_range(0..=SYMBOL_MAX_LEN)?;
+        let mut chars = [0u8; SYMBOL_MAX_LEN];
+        for c in chars.iter_mut().take(len) {
+            *c = *u.choose(SYMBOL_CHARS)?;
+        }
+        let s = core::str::from_utf8(&chars[..len]).map_err(|_| Error::IncorrectFormat)?;
+        Ok(Symbol::from_str(s))
+    }
+}
+
+impl ArbitraryVal for Bytes {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        let items = u.bytes(len as usize)?;
+        Ok(Bytes::from_slice(env, items))
+    }
+}
+
+impl ArbitraryVal for String {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let s: &str = Arbitrary::arbitrary(u)?;
+        Ok(String::from_str(env, s))
+    }
+}
+
+impl<const N: usize> ArbitraryVal for BytesN<N> {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let items: [u8; N] = Arbitrary::arbitrary(u)?;
+        Ok(BytesN::from_array(env, items))
+    }
+}
+
+impl ArbitraryVal for Account {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let pk: [u8; 32] = Arbitrary::arbitrary(u)?;
+        Ok(pk.into_val(env))
+    }
+}
+
+impl ArbitraryVal for Identifier {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(match u.int_in_range(0u32..=2)? {
+            0 => Identifier::Contract(BytesN::arbitrary_val(env, u)?),
+            1 => Identifier::Ed25519(BytesN::arbitrary_val(env, u)?),
+            _ => Identifier::Account(Account::arbitrary_val(env, u)?),
+        })
+    }
+}
+
+impl ArbitraryVal for BigInt {
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let limbs = u.int_in_range(0..=MAX_BIGINT_LIMBS)?;
+        let mut b = BigInt::zero(env);
+        for _ in 0..limbs {
+            let limb: u64 = Arbitrary::arbitrary(u)?;
+            b = (b << 64u32) + limb;
+        }
+        if bool::arbitrary(u)? {
+            b = -b;
+        }
+        Ok(b)
+    }
+}
+
+impl<T> ArbitraryVal for Vec<T>
+where
+    T: ArbitraryVal + IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        let mut vec = Vec::new(env);
+        for _ in 0..len {
+            vec.push(T::arbitrary_val(env, u)?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<K, V> ArbitraryVal for Map<K, V>
+where
+    K: ArbitraryVal + IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+    V: ArbitraryVal + IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        let mut map = Map::new(env);
+        for _ in 0..len {
+            map.set(K::arbitrary_val(env, u)?, V::arbitrary_val(env, u)?);
+        }
+        Ok(map)
+    }
+}
+
+macro_rules! impl_arbitrary_val_for_tuple {
+    ($($t:ident),+) => {
+        impl<$($t),+> ArbitraryVal for ($($t,)+)
+        where
+            $($t: ArbitraryVal),+
+        {
+            fn arbitrary_val(env: &Env, u: &mut Unstructured<'_>) -> Result<Self> {
+                Ok(($($t::arbitrary_val(env, u)?,)+))
+            }
+        }
+    };
+}
+
+impl_arbitrary_val_for_tuple!(A);
+impl_arbitrary_val_for_tuple!(A, B);
+impl_arbitrary_val_for_tuple!(A, B, C);
+impl_arbitrary_val_for_tuple!(A, B, C, D);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: [u8; 64] = [
+        7, 3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4, 6, 2, 6, 4, 3, 3, 8, 3, 2,
+        7, 9, 5, 0, 2, 8, 8, 4, 1, 9, 7, 1, 6, 9, 3, 9, 9, 3, 7, 5, 1, 0, 5, 8, 2, 0, 9, 7, 4, 9,
+        4, 4, 5, 9,
+    ];
+
+    #[test]
+    fn test_symbol() {
+        let env = Env::default();
+        let mut u = Unstructured::new(&DATA);
+        let s = Symbol::arbitrary_val(&env, &mut u).unwrap();
+        let s = s.to_str();
+        let s: &str = s.as_ref();
+        assert!(s.len() <= SYMBOL_MAX_LEN);
+    }
+
+    #[test]
+    fn test_collections() {
+        let env = Env::default();
+        let mut u = Unstructured::new(&DATA);
+        let v = Vec::<Bytes>::arbitrary_val(&env, &mut u).unwrap();
+        assert!(v.len() <= MAX_LEN);
+        let m = Map::<Symbol, BigInt>::arbitrary_val(&env, &mut u).unwrap();
+        assert!(m.len() <= MAX_LEN);
+    }
+
+    #[test]
+    fn test_fixed_size() {
+        let env = Env::default();
+        let mut u = Unstructured::new(&DATA);
+        let b = BytesN::<32>::arbitrary_val(&env, &mut u).unwrap();
+        assert_eq!(b.len(), 32);
+        let (_, b) = <(u32, BytesN<4>)>::arbitrary_val(&env, &mut u).unwrap();
+        assert_eq!(b.len(), 4);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let env = Env::default();
+        let mut u = Unstructured::new(&[]);
+        let v = Vec::<BigInt>::arbitrary_val(&env, &mut u).unwrap();
+        assert!(v.is_empty());
+    }
+}
@@ -0,0 +1,340 @@
+#![cfg(feature = "testutils")]
+
+//! Fuzzing of contracts using their contract spec.
+//!
+//! A [ContractFuzzer] decodes raw fuzzer input into a sequence of calls to the
+//! functions of a contract, generating arguments that match the types in the
+//! contract's spec. Each run executes in a fresh [Env].
+//!
+//! Arguments of the SDK's [Identifier] and [Account] types are generated
+//! without their specs being added, using the names the SDK's own spec of
+//! [Identifier] gives them. A spec added with the same name as an SDK type
+//! replaces it. Versioned contract types must be added with
+//! [ContractFuzzer::with_versioned_type_spec] so that their `_version` field
+//! is generated with the version the type accepts.
+//!
+//! Panics in the contract are resumed so that the fuzzer reports them, unless
+//! the panic carries a [Status] that has been allowed with
+//! [ContractFuzzer::allow_status], in which case the run ends quietly.
+//!
+//! ### Examples
+//!
+//! A `cargo-fuzz` target for a contract:
+//!
+//! ```
+//! use soroban_sdk::{contractimpl, testutils::fuzz::ContractFuzzer};
+//!
+//! pub struct Contract;
+//!
+//! #[contractimpl]
+//! impl Contract {
+//!     pub fn add(a: u32, b: u32) -> u64 {
+//!         a as u64 + b as u64
+//!     }
+//! }
+//!
+//! # fn main() {
+//! # let data = [0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+//! // Called with the data given to a fuzz_target!.
+//! ContractFuzzer::new(Contract).run(&data);
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    io::Cursor,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    string::String,
+};
+
+use super::{
+    arbitrary::{ArbitraryVal, Error, Result, Unstructured, MAX_LEN},
+    ContractFunctionSet, ContractFunctionSpec,
+};
+use crate::{
+    xdr::{ReadXdr, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef},
+    Account, BigInt, Bytes, BytesN, Env, EnvVal, Identifier, IntoVal, Map, RawVal, Status, Symbol,
+    Vec, Versioned,
+};
+
+/// The maximum number of calls made to the contract in a single run.
+pub const MAX_CALLS: u32 = 16;
+
+/// The maximum depth of nested values generated for arguments.
+pub const MAX_DEPTH: u32 = 4;
+
+/// ContractFuzzer executes sequences of calls decoded from fuzzer input
+/// against a contract.
+pub struct ContractFuzzer<T> {
+    contract: T,
+    types: HashMap<String, TypeSpec>,
+    allowed: std::vec::Vec<Status>,
+}
+
+/// How arguments of a user-defined type are generated.
+enum TypeSpec {
+    /// A contract type generated from its spec, and its version if it is
+    /// versioned.
+    Spec {
+        entry: ScSpecEntry,
+        version: Option<u32>,
+    },
+    /// A type defined in the SDK, generated by its [ArbitraryVal] impl.
+    Sdk(fn(&Env, &mut Unstructured<'_>) -> Result<RawVal>),
+}
+
+impl<T> ContractFuzzer<T>
+where
+    T: ContractFunctionSet + ContractFunctionSpec + 'static,
+{
+    /// Create a fuzzer for the contract.
+    pub fn new(contract: T) -> Self {
+        Self {
+            contract,
+            types: sdk_types(),
+            allowed: std::vec::Vec::new(),
+        }
+    }
+
+    /// Add the spec of a user-defined type, so that arguments of that type
+    /// can be generated.
+    ///
+    /// ### Panics
+    ///
+    /// If the spec is not a valid XDR encoded spec entry.
+    #[must_use]
+    pub fn with_type_spec(self, spec_xdr: &[u8]) -> Self {
+        self.with_spec(spec_xdr, None)
+    }
+
+    /// Add the spec of a versioned user-defined type, so that arguments of
+    /// that type can be generated with the version the type accepts.
+    ///
+    /// ### Panics
+    ///
+    /// If the spec is not a valid XDR encoded spec entry.
+    #[must_use]
+    pub fn with_versioned_type_spec<V: Versioned>(self, spec_xdr: &[u8]) -> Self {
+        self.with_spec(spec_xdr, Some(V::VERSION))
+    }
+
+    fn with_spec(mut self, spec_xdr: &[u8], version: Option<u32>) -> Self {
+        let entry = ScSpecEntry::read_xdr(&mut Cursor::new(spec_xdr)).unwrap();
+        let name = match &entry {
+            ScSpecEntry::UdtStructV0(s) => s.name.to_string(),
+            ScSpecEntry::UdtUnionV0(u) => u.name.to_string(),
+            _ => return self,
+        };
+        self.types
+            .insert(name.unwrap(), TypeSpec::Spec { entry, version });
+        self
+    }
+
+    /// Allow the contract to fail with the status, ending the run without
+    /// reporting a failure.
+    #[must_use]
+    pub fn allow_status(mut self, status: Status) -> Self {
+        self.allowed.push(status);
+        self
+    }
+
+    /// Run the sequence of calls decoded from the data.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract panics with anything other than an allowed status.
+    pub fn run(self, data: &[u8]) {
+        let ContractFuzzer {
+            contract,
+            types,
+            allowed,
+        } = self;
+
+        let functions: std::vec::Vec<ScSpecFunctionV0> = contract
+            .spec_xdrs()
+            .iter()
+            .filter_map(|xdr| match ScSpecEntry::read_xdr(&mut Cursor::new(xdr)) {
+                Ok(ScSpecEntry::FunctionV0(f)) => Some(f),
+                _ => None,
+            })
+            .collect();
+        if functions.is_empty() {
+            return;
+        }
+
+        let env = Env::default();
+        let contract_id = BytesN::from_array(&env, [0; 32]);
+        env.register_contract(&contract_id, contract);
+
+        let mut u = Unstructured::new(data);
+        for _ in 0..MAX_CALLS {
+            if u.is_empty() {
+                break;
+            }
+            let (func, args) = match arbitrary_call(&env, &types, &functions, &mut u) {
+                Ok(call) => call,
+                Err(_) => break,
+            };
+            let res = catch_unwind(AssertUnwindSafe(|| {
+                env.invoke_contract::<EnvVal>(&contract_id, &func, args)
+            }));
+            if let Err(e) = res {
+                let expected = e
+                    .downcast_ref::<Status>()
+                    .map_or(false, |st| allowed.contains(st))
+                    || allowed
+                        .iter()
+                        .any(|st| env.last_debug_event_has_status(*st));
+                if expected {
+                    break;
+                }
+                resume_unwind(e);
+            }
+        }
+    }
+}
+
+/// Returns the types defined in the SDK, named as they are in the SDK's spec
+/// of [Identifier]. [Account] has no spec of its own and is the user-defined
+/// type of one of the cases of [Identifier].
+fn sdk_types() -> HashMap<String, TypeSpec> {
+    let mut types = HashMap::new();
+    let entry = ScSpecEntry::read_xdr(&mut Cursor::new(&crate::__SPEC_XDR_IDENTIFIER[..])).unwrap();
+    if let ScSpecEntry::UdtUnionV0(identifier) = entry {
+        for case in identifier.cases.iter() {
+            if let Some(ScSpecTypeDef::Udt(udt)) = &case.type_ {
+                types.insert(
+                    udt.name.to_string().unwrap(),
+                    TypeSpec::Sdk(|env, u| Ok(RawVal::from(Account::arbitrary_val(env, u)?))),
+                );
+            }
+        }
+        types.insert(
+            identifier.name.to_string().unwrap(),
+            TypeSpec::Sdk(|env, u| Ok(raw_val(env, Identifier::arbitrary_val(env, u)?))),
+        );
+    }
+    types
+}
+
+fn arbitrary_call(
+    env: &Env,
+    types: &HashMap<String, TypeSpec>,
+    functions: &[ScSpecFunctionV0],
+    u: &mut Unstructured<'_>,
+) -> Result<(Symbol, Vec<EnvVal>)> {
+    let f = u.choose(functions)?;
+    let name = f.name.to_string().map_err(|_| Error::IncorrectFormat)?;
+    let mut args = Vec::<EnvVal>::new(env);
+    for ty in f.input_types.iter() {
+        args.push(env_val(env, arbitrary_raw_val(env, types, ty, 0, u)?));
+    }
+    Ok((Symbol::from_str(&name), args))
+}
+
+fn env_val(env: &Env, val: RawVal) -> EnvVal {
+    EnvVal {
+        env: env.clone(),
+        val,
+    }
+}
+
+fn raw_val<V: IntoVal<Env, RawVal>>(env: &Env, v: V) -> RawVal {
+    v.into_val(env)
+}
+
+fn arbitrary_raw_val(
+    env: &Env,
+    types: &HashMap<String, TypeSpec>,
+    ty: &ScSpecTypeDef,
+    depth: u32,
+    u: &mut Unstructured<'_>,
+) -> Result<RawVal> {
+    if depth > MAX_DEPTH {
+        return Err(Error::IncorrectFormat);
+    }
+    let depth = depth + 1;
+    Ok(match ty {
+        ScSpecTypeDef::U64 => raw_val(env, u64::arbitrary_val(env, u)?),
+        ScSpecTypeDef::I64 => raw_val(env, i64::arbitrary_val(env, u)?),
+        ScSpecTypeDef::U32 => raw_val(env, u32::arbitrary_val(env, u)?),
+        ScSpecTypeDef::I32 => raw_val(env, i32::arbitrary_val(env, u)?),
+        ScSpecTypeDef::Bool => raw_val(env, bool::arbitrary_val(env, u)?),
+        ScSpecTypeDef::Symbol => raw_val(env, Symbol::arbitrary_val(env, u)?),
+        ScSpecTypeDef::Binary => raw_val(env, Bytes::arbitrary_val(env, u)?),
+        ScSpecTypeDef::BigInt => raw_val(env, BigInt::arbitrary_val(env, u)?),
+        ScSpecTypeDef::Option(o) => {
+            if bool::arbitrary_val(env, u)? {
+                arbitrary_raw_val(env, types, &o.value_type, depth, u)?
+            } else {
+                ().into()
+            }
+        }
+        ScSpecTypeDef::Vec(v) => {
+            let len = u.int_in_range(0..=MAX_LEN)?;
+            let mut vec = Vec::<EnvVal>::new(env);
+            for _ in 0..len {
+                let val = arbitrary_raw_val(env, types, &v.element_type, depth, u)?;
+                vec.push(env_val(env, val));
+            }
+            vec.into()
+        }
+        ScSpecTypeDef::Map(m) => {
+            let len = u.int_in_range(0..=MAX_LEN)?;
+            let mut map = Map::<EnvVal, EnvVal>::new(env);
+            for _ in 0..len {
+                let k = arbitrary_raw_val(env, types, &m.key_type, depth, u)?;
+                let v = arbitrary_raw_val(env, types, &m.value_type, depth, u)?;
+                map.set(env_val(env, k), env_val(env, v));
+            }
+            map.into()
+        }
+        ScSpecTypeDef::Tuple(t) => {
+            let mut vec = Vec::<EnvVal>::new(env);
+            for ty in t.value_types.iter() {
+                let val = arbitrary_raw_val(env, types, ty, depth, u)?;
+                vec.push(env_val(env, val));
+            }
+            vec.into()
+        }
+        ScSpecTypeDef::Udt(udt) => {
+            let name = udt.name.to_string().map_err(|_| Error::IncorrectFormat)?;
+            match types.get(&name) {
+                Some(TypeSpec::Spec {
+                    entry: ScSpecEntry::UdtStructV0(s),
+                    version,
+                }) => {
+                    let mut map = Map::<Symbol, EnvVal>::new(env);
+                    for f in s.fields.iter() {
+                        let name = f.name.to_string().map_err(|_| Error::IncorrectFormat)?;
+                        let val = match (&name[..], *version) {
+                            ("_version", Some(version)) => version.into(),
+                            ("_version", None) => return Err(Error::IncorrectFormat),
+                            _ => arbitrary_raw_val(env, types, &f.type_, depth, u)?,
+                        };
+                        map.set(Symbol::from_str(&name), env_val(env, val));
+                    }
+                    map.into()
+                }
+                Some(TypeSpec::Spec {
+                    entry: ScSpecEntry::UdtUnionV0(un),
+                    ..
+                }) => {
+                    let case = u.choose(&un.cases[..])?;
+                    let name = case.name.to_string().map_err(|_| Error::IncorrectFormat)?;
+                    let val = match &case.type_ {
+                        Some(ty) => arbitrary_raw_val(env, types, ty, depth, u)?,
+                        None => ().into(),
+                    };
+                    let mut vec = Vec::<EnvVal>::new(env);
+                    vec.push(env_val(env, Symbol::from_str(&name).into()));
+                    vec.push(env_val(env, val));
+                    vec.into()
+                }
+                Some(TypeSpec::Sdk(arbitrary)) => arbitrary(env, u)?,
+                _ => return Err(Error::IncorrectFormat),
+            }
+        }
+        _ => return Err(Error::IncorrectFormat),
+    })
+}
@@ -9,13 +9,19 @@ pub trait Sign<MSG> {
     fn sign(&self, m: MSG) -> Result<Self::Signature, Self::Error>;
 }
 
-// TODO: Add a Verify interface and ed25519 implementation to counter the Sign
-// interface.
+/// Verify implementations check signatures for types that can be represented
+/// as the MSG.
+pub trait Verify<MSG> {
+    type Signature;
+    type Error;
+    /// Verify checks the signature is valid for the MSG.
+    fn verify(&self, m: MSG, s: &Self::Signature) -> Result<(), Self::Error>;
+}
 
 pub mod ed25519 {
     use xdr::WriteXdr;
 
-    use crate::xdr;
+    use crate::{xdr, Bytes, BytesN, Env, IntoVal};
 
     #[derive(Debug)]
     pub enum Error<E: std::error::Error> {
@@ -57,7 +63,7 @@ pub mod ed25519 {
         }
     }
 
-    pub use super::Sign;
+    pub use super::{Sign, Verify};
 
     impl<S, M> super::Sign<M> for S
     where
@@ -75,11 +81,83 @@ pub mod ed25519 {
         }
     }
 
+    impl<V, M> super::Verify<M> for V
+    where
+        V: ed25519_dalek::Verifier<ed25519_dalek::Signature>,
+        M: TryInto<xdr::ScVal>,
+        <M as TryInto<xdr::ScVal>>::Error: std::error::Error,
+    {
+        type Error = Error<<M as TryInto<xdr::ScVal>>::Error>;
+        type Signature = [u8; 64];
+        fn verify(&self, m: M, s: &Self::Signature) -> Result<(), Self::Error> {
+            let mut buf = Vec::<u8>::new();
+            let val: xdr::ScVal = m.try_into().map_err(|e| Self::Error::ConversionError(e))?;
+            val.write_xdr(&mut buf)?;
+            let sig = ed25519_dalek::Signature::from_bytes(s)?;
+            Ok(ed25519_dalek::Verifier::verify(self, &buf, &sig)?)
+        }
+    }
+
+    /// Generate a keypair using the random number generator, returning the
+    /// public key as a [BytesN] ready to be passed to contracts.
+    pub fn generate<R>(env: &Env, rng: &mut R) -> (BytesN<32>, ed25519_dalek::Keypair)
+    where
+        R: rand::CryptoRng + rand::RngCore,
+    {
+        let kp = ed25519_dalek::Keypair::generate(rng);
+        (kp.public.to_bytes().into_val(env), kp)
+    }
+
+    /// Create the keypair deterministically from the seed, returning the
+    /// public key as a [BytesN] ready to be passed to contracts.
+    ///
+    /// The seed is used as the ed25519 secret key.
+    pub fn from_seed(env: &Env, seed: &[u8; 32]) -> (BytesN<32>, ed25519_dalek::Keypair) {
+        let secret = ed25519_dalek::SecretKey::from_bytes(seed).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let kp = ed25519_dalek::Keypair { secret, public };
+        (kp.public.to_bytes().into_val(env), kp)
+    }
+
+    /// Sign the bytes of the message as is, producing the signature that
+    /// [Env::verify_sig_ed25519] accepts for the message.
+    ///
+    /// Unlike [Sign], the message is not XDR encoded before signing.
+    ///
+    /// ### Panics
+    ///
+    /// If the signer fails to sign the message.
+    pub fn sign_payload<S>(signer: &S, msg: &Bytes) -> BytesN<64>
+    where
+        S: ed25519_dalek::Signer<ed25519_dalek::Signature>,
+    {
+        let buf: Vec<u8> = msg.iter().collect();
+        let sig = signer.try_sign(&buf).unwrap();
+        sig.to_bytes().into_val(msg.env())
+    }
+
+    /// Verify the signature of the bytes of the message as is, the same way
+    /// [Env::verify_sig_ed25519] does, but returning an error instead of
+    /// panicking.
+    pub fn verify_payload(
+        pk: &BytesN<32>,
+        msg: &Bytes,
+        sig: &BytesN<64>,
+    ) -> Result<(), ed25519_dalek::SignatureError> {
+        let pk: Vec<u8> = pk.iter().collect();
+        let sig: Vec<u8> = sig.iter().collect();
+        let buf: Vec<u8> = msg.iter().collect();
+        let pk = ed25519_dalek::PublicKey::from_bytes(&pk)?;
+        let sig = ed25519_dalek::Signature::from_bytes(&sig)?;
+        ed25519_dalek::Verifier::verify(&pk, &buf, &sig)
+    }
+
     #[cfg(test)]
     mod test {
         use ed25519_dalek::{Keypair, PublicKey, SecretKey};
 
-        use super::Sign;
+        use super::{Sign, Verify};
+        use crate::{Bytes, Env};
 
         #[test]
         fn sign() {
@@ -100,5 +178,37 @@ pub mod ed25519 {
                 "082f78fb1864f6914de4c3c4e3e0c6e7c63a6a866aa81bda8042f74155cb95e7d29958061a11568f03db137cbf17c8b7bbf6193b2901af9888bbdf150c7be00a",
             );
         }
+
+        #[test]
+        fn verify() {
+            let env = Env::default();
+            let (_, kp) = super::from_seed(&env, &[7; 32]);
+            let sig = kp.sign(128i64).unwrap();
+            assert!(kp.public.verify(128i64, &sig).is_ok());
+            assert!(kp.public.verify(129i64, &sig).is_err());
+        }
+
+        #[test]
+        fn from_seed_is_deterministic() {
+            let env = Env::default();
+            let (pk1, _) = super::from_seed(&env, &[7; 32]);
+            let (pk2, _) = super::from_seed(&env, &[7; 32]);
+            let (pk3, _) = super::from_seed(&env, &[8; 32]);
+            assert_eq!(pk1, pk2);
+            assert_ne!(pk1, pk3);
+        }
+
+        #[test]
+        fn sign_payload() {
+            let env = Env::default();
+            let (pk, kp) = super::generate(&env, &mut rand::thread_rng());
+            let msg = Bytes::from_slice(&env, b"hello world");
+            let sig = super::sign_payload(&kp, &msg);
+            assert!(super::verify_payload(&pk, &msg, &sig).is_ok());
+            env.verify_sig_ed25519(pk.clone(), msg, sig.clone());
+
+            let other = Bytes::from_slice(&env, b"hello there");
+            assert!(super::verify_payload(&pk, &other, &sig).is_err());
+        }
     }
 }
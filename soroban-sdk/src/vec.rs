@@ -6,7 +6,7 @@ use core::{
     ops::{Bound, RangeBounds},
 };
 
-use crate::iter::{UncheckedEnumerable, UncheckedIter};
+use crate::iter::{FromIteratorIn, UncheckedEnumerable, UncheckedIter};
 
 use super::{
     env::internal::Env as _,
@@ -291,6 +291,14 @@ where
         vec
     }
 
+    /// Create a vec containing the items of the iterator.
+    #[inline(always)]
+    pub fn from_iter<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Vec<T> {
+        let mut vec = Vec::new(env);
+        vec.extend(iter);
+        vec
+    }
+
     #[inline(always)]
     pub fn get(&self, i: u32) -> Option<Result<T, T::Error>> {
         if i < self.len() {
@@ -460,6 +468,172 @@ where
         unsafe { Self::unchecked_new(vec) }
     }
 
+    /// Returns true if the vec contains an element equal to the value.
+    #[inline(always)]
+    pub fn contains(&self, x: T) -> bool {
+        self.first_index_of(x).is_some()
+    }
+
+    /// Returns the index of the first element in the vec that is equal to
+    /// the value, or [None] if no element is equal to the value.
+    pub fn first_index_of(&self, x: T) -> Option<u32> {
+        let env = self.env();
+        let x = x.into_val(env);
+        (0..self.len()).find(|i| cmp_raw(env, self.get_raw(*i), x) == Ordering::Equal)
+    }
+
+    /// Returns the index of the last element in the vec that is equal to
+    /// the value, or [None] if no element is equal to the value.
+    pub fn last_index_of(&self, x: T) -> Option<u32> {
+        let env = self.env();
+        let x = x.into_val(env);
+        (0..self.len())
+            .rev()
+            .find(|i| cmp_raw(env, self.get_raw(*i), x) == Ordering::Equal)
+    }
+
+    /// Searches a sorted vec for the value, using the ordering of values in
+    /// the host.
+    ///
+    /// If the value is found, returns [Result::Ok] with the index of a
+    /// matching element. If the value is not found, returns [Result::Err]
+    /// with the index at which the value could be inserted to keep the vec
+    /// sorted.
+    ///
+    /// If the vec is not sorted the result is unspecified.
+    pub fn binary_search(&self, x: T) -> Result<u32, u32> {
+        let env = self.env();
+        let x = x.into_val(env);
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match cmp_raw(env, self.get_raw(mid), x) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Sorts the vec using the ordering of values in the host.
+    ///
+    /// The sort is stable, elements that are equal keep their order.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, Env};
+    ///
+    /// let env = Env::default();
+    /// let mut v = vec![&env, 3, 1, 2];
+    /// v.sort();
+    /// assert_eq!(v, vec![&env, 1, 2, 3]);
+    /// ```
+    pub fn sort(&mut self) {
+        let env = self.env().clone();
+        self.sort_raw(|a, b| cmp_raw(&env, a, b));
+    }
+
+    /// Sorts the vec by the key returned by the function for each element,
+    /// using the ordering of the keys in the host.
+    ///
+    /// The sort is stable, elements with equal keys keep their order.
+    ///
+    /// ### Panics
+    ///
+    /// When an element in the vec cannot be converted into the type
+    /// expected.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        T::Error: Debug,
+        K: IntoVal<Env, RawVal>,
+        F: FnMut(&T) -> K,
+    {
+        let env = self.env().clone();
+        self.sort_raw(|a, b| {
+            let a = f(&T::try_from_val(&env, a).unwrap()).into_val(&env);
+            let b = f(&T::try_from_val(&env, b).unwrap()).into_val(&env);
+            cmp_raw(&env, a, b)
+        });
+    }
+
+    /// Removes consecutive elements that are equal, leaving only the first
+    /// of each run of equal elements.
+    ///
+    /// If the vec is sorted this removes all duplicates.
+    pub fn dedup(&mut self) {
+        let env = self.env().clone();
+        let mut vec = env.vec_new(().into());
+        let mut prev: Option<RawVal> = None;
+        for i in 0..self.len() {
+            let x = self.get_raw(i);
+            if prev.map_or(true, |p| cmp_raw(&env, p, x) != Ordering::Equal) {
+                vec = env.vec_push(vec, x);
+            }
+            prev = Some(x);
+        }
+        self.0 = vec.in_env(&env);
+    }
+
+    /// Reverses the order of the elements in the vec.
+    pub fn reverse(&mut self) {
+        let env = self.env().clone();
+        let mut vec = env.vec_new(().into());
+        for i in (0..self.len()).rev() {
+            vec = env.vec_push(vec, self.get_raw(i));
+        }
+        self.0 = vec.in_env(&env);
+    }
+
+    #[inline(always)]
+    fn get_raw(&self, i: u32) -> RawVal {
+        self.env().vec_get(self.0.to_object(), i.into())
+    }
+
+    // Bottom-up merge sort that merges adjacent runs into a new vec on each
+    // pass, so that the sort is stable and only pushes to host vecs.
+    fn sort_raw<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(RawVal, RawVal) -> Ordering,
+    {
+        let env = self.env().clone();
+        let len = self.len();
+        let mut src = self.0.to_object();
+        let mut width = 1;
+        while width < len {
+            let mut dst = env.vec_new(().into());
+            let mut start = 0;
+            while start < len {
+                let mid = len.min(start + width);
+                let end = len.min(start + 2 * width);
+                let (mut i, mut j) = (start, mid);
+                while i < mid || j < end {
+                    let take_left = if i < mid && j < end {
+                        let a = env.vec_get(src, i.into());
+                        let b = env.vec_get(src, j.into());
+                        cmp(a, b) != Ordering::Greater
+                    } else {
+                        i < mid
+                    };
+                    let x = if take_left {
+                        i += 1;
+                        env.vec_get(src, (i - 1).into())
+                    } else {
+                        j += 1;
+                        env.vec_get(src, (j - 1).into())
+                    };
+                    dst = env.vec_push(dst, x);
+                }
+                start = end;
+            }
+            src = dst;
+            width *= 2;
+        }
+        self.0 = src.in_env(&env);
+    }
+
     pub fn iter(&self) -> VecIter<T>
     where
         T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal> + Clone,
@@ -486,6 +660,13 @@ where
     }
 }
 
+#[inline(always)]
+fn cmp_raw(env: &Env, a: RawVal, b: RawVal) -> Ordering {
+    let v = env.obj_cmp(a, b);
+    let i = i32::try_from(v).unwrap();
+    i.cmp(&0)
+}
+
 impl<T> Vec<Vec<T>>
 where
     T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -501,6 +682,26 @@ where
     }
 }
 
+impl<T> Extend<T> for Vec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> FromIteratorIn<T> for Vec<T>
+where
+    T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
+{
+    fn from_iter_in<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Self {
+        Vec::from_iter(env, iter)
+    }
+}
+
 impl<T> IntoIterator for Vec<T>
 where
     T: IntoVal<Env, RawVal> + TryFromVal<Env, RawVal>,
@@ -770,6 +971,82 @@ mod test {
         assert_eq!(iter.next_back(), None);
     }
 
+    #[test]
+    fn test_vec_search() {
+        let env = Env::default();
+
+        let vec = vec![&env, 1, 3, 3, 5];
+        assert!(vec.contains(3));
+        assert!(!vec.contains(4));
+        assert_eq!(vec.first_index_of(3), Some(1));
+        assert_eq!(vec.last_index_of(3), Some(2));
+        assert_eq!(vec.first_index_of(4), None);
+        assert_eq!(vec.last_index_of(4), None);
+
+        assert_eq!(vec.binary_search(1), Ok(0));
+        assert_eq!(vec.binary_search(5), Ok(3));
+        assert!(matches!(vec.binary_search(3), Ok(1) | Ok(2)));
+        assert_eq!(vec.binary_search(0), Err(0));
+        assert_eq!(vec.binary_search(4), Err(3));
+        assert_eq!(vec.binary_search(6), Err(4));
+
+        let vec: Vec<u32> = vec![&env];
+        assert_eq!(vec.binary_search(1), Err(0));
+        assert!(!vec.contains(1));
+    }
+
+    #[test]
+    fn test_vec_sort() {
+        let env = Env::default();
+
+        let mut vec: Vec<i64> = vec![&env];
+        vec.sort();
+        assert_eq!(vec, vec![&env]);
+
+        let mut vec = vec![&env, 5, 2, 9, 1, 5, 6, 0];
+        vec.sort();
+        assert_eq!(vec, vec![&env, 0, 1, 2, 5, 5, 6, 9]);
+
+        let mut vec = vec![&env, (3, 0), (1, 1), (3, 2), (2, 3), (1, 4)];
+        vec.sort_by_key(|(k, _): &(i64, i64)| *k);
+        assert_eq!(vec, vec![&env, (1, 1), (1, 4), (2, 3), (3, 0), (3, 2)]);
+    }
+
+    #[test]
+    fn test_vec_dedup_reverse() {
+        let env = Env::default();
+
+        let mut vec = vec![&env, 1, 1, 2, 3, 3, 3, 1];
+        vec.dedup();
+        assert_eq!(vec, vec![&env, 1, 2, 3, 1]);
+
+        vec.reverse();
+        assert_eq!(vec, vec![&env, 1, 3, 2, 1]);
+
+        let mut vec: Vec<i64> = vec![&env];
+        vec.dedup();
+        vec.reverse();
+        assert_eq!(vec, vec![&env]);
+    }
+
+    #[test]
+    fn test_vec_from_iter() {
+        use crate::iter::CollectIn;
+
+        let env = Env::default();
+
+        let vec = Vec::from_iter(&env, [1, 2, 3]);
+        assert_eq!(vec, vec![&env, 1, 2, 3]);
+
+        let vec: Vec<i64> = vec.iter_unchecked().map(|x| x * 2).collect_in(&env);
+        assert_eq!(vec, vec![&env, 2, 4, 6]);
+
+        let mut vec = vec![&env, 1];
+        vec.extend(vec![&env, 2, 3].iter_unchecked());
+        vec.extend([4]);
+        assert_eq!(vec, vec![&env, 1, 2, 3, 4]);
+    }
+
     #[cfg(not(target_family = "wasm"))]
     #[test]
     fn test_scval_accessibility_from_udt_types() {
@@ -0,0 +1,180 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{
+    contractimpl, testutils::ed25519, Account, AccountError, Bytes, BytesN, Env, Threshold, Vec,
+};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn exists(env: Env, pk: BytesN<32>) -> bool {
+        let exists = Account::exists(&env, &pk);
+        assert_eq!(exists, Account::from_public_key(&pk).is_ok());
+        exists
+    }
+
+    pub fn thresholds(pk: BytesN<32>) -> (u32, u32, u32) {
+        let a = Account::from_public_key(&pk).unwrap();
+        (a.low_threshold(), a.medium_threshold(), a.high_threshold())
+    }
+
+    pub fn weight(pk: BytesN<32>, signer: BytesN<32>) -> u32 {
+        let a = Account::from_public_key(&pk).unwrap();
+        a.signer_weight(&signer)
+    }
+
+    pub fn verify(
+        env: Env,
+        pk: BytesN<32>,
+        msg: Bytes,
+        sigs: Vec<(BytesN<32>, BytesN<64>)>,
+    ) -> u32 {
+        let a = Account::from_public_key(&pk).unwrap();
+        match a.verify_signatures(&env, &msg, &sigs, Threshold::Medium) {
+            Ok(()) => 0,
+            Err(AccountError::DoesNotExist) => 1,
+            Err(AccountError::DuplicateSigner) => 2,
+            Err(AccountError::ThresholdNotMet) => 3,
+        }
+    }
+}
+
+#[test]
+fn test_create_account() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let pk = BytesN::from_array(&e, [1; 32]);
+    assert!(!e.accounts().exists(&pk));
+    e.accounts().create(&pk);
+    assert!(e.accounts().exists(&pk));
+
+    assert_eq!(thresholds::invoke(&e, &contract_id, &pk), (0, 0, 0));
+    assert_eq!(weight::invoke(&e, &contract_id, &pk, &pk), 1);
+}
+
+#[test]
+fn test_thresholds_and_signers() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let pk = BytesN::from_array(&e, [1; 32]);
+    let signer = BytesN::from_array(&e, [2; 32]);
+    e.accounts().create(&pk);
+    e.accounts().set_thresholds(&pk, 1, 2, 3);
+    e.accounts().set_master_weight(&pk, 2);
+    e.accounts().set_signer_weight(&pk, &signer, 3);
+
+    assert_eq!(thresholds::invoke(&e, &contract_id, &pk), (1, 2, 3));
+    assert_eq!(weight::invoke(&e, &contract_id, &pk, &pk), 2);
+    assert_eq!(weight::invoke(&e, &contract_id, &pk, &signer), 3);
+
+    e.accounts().remove_signer(&pk, &signer);
+    assert_eq!(weight::invoke(&e, &contract_id, &pk, &signer), 0);
+}
+
+#[test]
+#[should_panic(expected = "account already exists")]
+fn test_create_existing_account() {
+    let e = Env::default();
+    let pk = BytesN::from_array(&e, [1; 32]);
+    e.accounts().create(&pk);
+    e.accounts().create(&pk);
+}
+
+#[test]
+fn test_remove_account() {
+    let e = Env::default();
+    let pk = BytesN::from_array(&e, [1; 32]);
+    e.accounts().create(&pk);
+    e.accounts().remove(&pk);
+    assert!(!e.accounts().exists(&pk));
+}
+
+#[test]
+fn test_missing_account() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let pk = BytesN::from_array(&e, [1; 32]);
+    assert!(!exists::invoke(&e, &contract_id, &pk));
+    assert_eq!(
+        Account::from_public_key(&pk),
+        Err(AccountError::DoesNotExist)
+    );
+
+    e.accounts().create(&pk);
+    assert!(exists::invoke(&e, &contract_id, &pk));
+}
+
+#[test]
+fn test_verify_signatures() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let (pk, kp) = ed25519::from_seed(&e, &[1; 32]);
+    let (signer, signer_kp) = ed25519::from_seed(&e, &[2; 32]);
+    let (other, other_kp) = ed25519::from_seed(&e, &[3; 32]);
+    e.accounts().create(&pk);
+    e.accounts().set_thresholds(&pk, 1, 3, 5);
+    e.accounts().set_signer_weight(&pk, &signer, 2);
+
+    let msg = Bytes::from_slice(&e, b"hello");
+    let sig = ed25519::sign_payload(&kp, &msg);
+    let signer_sig = ed25519::sign_payload(&signer_kp, &msg);
+    let other_sig = ed25519::sign_payload(&other_kp, &msg);
+
+    let sigs = Vec::from_array(&e, [(pk.clone(), sig.clone())]);
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 3);
+
+    let sigs = Vec::from_array(
+        &e,
+        [
+            (pk.clone(), sig.clone()),
+            (other.clone(), other_sig.clone()),
+        ],
+    );
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 3);
+
+    let sigs = Vec::from_array(
+        &e,
+        [
+            (signer.clone(), signer_sig.clone()),
+            (signer.clone(), signer_sig.clone()),
+        ],
+    );
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 2);
+
+    let sigs = Vec::from_array(&e, [(pk.clone(), sig), (signer, signer_sig)]);
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 0);
+}
+
+#[test]
+fn test_verify_signatures_default_thresholds() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let (pk, kp) = ed25519::from_seed(&e, &[1; 32]);
+    let (other, other_kp) = ed25519::from_seed(&e, &[3; 32]);
+    e.accounts().create(&pk);
+    assert_eq!(thresholds::invoke(&e, &contract_id, &pk), (0, 0, 0));
+
+    let msg = Bytes::from_slice(&e, b"hello");
+
+    let sigs = Vec::new(&e);
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 3);
+
+    let other_sig = ed25519::sign_payload(&other_kp, &msg);
+    let sigs = Vec::from_array(&e, [(other, other_sig)]);
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 3);
+
+    let sig = ed25519::sign_payload(&kp, &msg);
+    let sigs = Vec::from_array(&e, [(pk.clone(), sig)]);
+    assert_eq!(verify::invoke(&e, &contract_id, &pk, &msg, &sigs), 0);
+}
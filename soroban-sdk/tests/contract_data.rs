@@ -0,0 +1,226 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, BytesN, ContractData, Env, Map, Symbol, Vec};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn incr(env: Env) -> u32 {
+        env.contract_data()
+            .update(Symbol::from_str("count"), |v: Option<u32>| {
+                v.unwrap_or(0) + 1
+            })
+    }
+
+    pub fn count(env: Env) -> u32 {
+        env.contract_data()
+            .get_or_default(Symbol::from_str("count"))
+    }
+
+    pub fn count_or(env: Env, default: u32) -> u32 {
+        env.contract_data()
+            .get_or(Symbol::from_str("count"), default)
+    }
+
+    pub fn take(env: Env) -> Option<u32> {
+        env.contract_data().take(Symbol::from_str("count"))
+    }
+
+    pub fn ops(env: Env, cached: bool) {
+        if cached {
+            let mut data = env.contract_data().cached();
+            apply_ops(|k, v| match v {
+                Some(v) => data.set(k, v),
+                None => data.remove(k),
+            });
+            data.commit();
+        } else {
+            let data = env.contract_data();
+            apply_ops(|k, v| match v {
+                Some(v) => data.set(k, v),
+                None => data.remove(k),
+            });
+        }
+    }
+
+    pub fn dump(env: Env) -> Map<u32, Option<u32>> {
+        let data = env.contract_data();
+        let mut m = Map::new(&env);
+        for k in 0..5u32 {
+            m.set(k, data.try_get(k).unwrap());
+        }
+        m
+    }
+
+    pub fn uncommitted(env: Env) -> Vec<bool> {
+        let mut cache = env.contract_data().cached();
+        let data: ContractData = env.contract_data();
+        let key = Symbol::from_str("k");
+        cache.set(key, 1u32);
+        let mut seen = Vec::new(&env);
+        seen.push(cache.has(key));
+        seen.push(data.has(key));
+        cache.commit();
+        seen.push(data.has(key));
+        cache.remove(key);
+        seen.push(cache.has(key));
+        seen.push(data.has(key));
+        drop(cache);
+        seen.push(data.has(key));
+        seen
+    }
+
+    pub fn overflow(env: Env) -> Vec<u32> {
+        let mut cache = env.contract_data().cached_with_capacity::<2>();
+        for k in 0..4u32 {
+            cache.set(k, k * 10);
+        }
+        cache.remove(0u32);
+        cache.commit();
+        let data = env.contract_data();
+        let mut vals = Vec::new(&env);
+        for k in 0..4u32 {
+            vals.push(data.get_or(k, 99u32));
+        }
+        vals
+    }
+
+    pub fn overflow_order(env: Env) -> Vec<u32> {
+        let mut cache = env.contract_data().cached_with_capacity::<2>();
+        let data = env.contract_data();
+        let mut vals = Vec::new(&env);
+        for k in 0..3u32 {
+            cache.set(k, k * 10);
+            for j in 0..3u32 {
+                vals.push(data.get_or(j, 99u32));
+            }
+        }
+        vals
+    }
+
+    pub fn dropped(env: Env) -> u32 {
+        {
+            let mut cache = env.contract_data().cached();
+            cache.set(Symbol::from_str("count"), 5u32);
+        }
+        env.contract_data().get_or(Symbol::from_str("count"), 0)
+    }
+
+    pub fn try_get(env: Env) -> bool {
+        let data = env.contract_data();
+        let key = Symbol::from_str("count");
+        match (data.try_get::<_, u32>(key), data.try_get::<_, Symbol>(key)) {
+            (Ok(None), Ok(None)) => true,
+            (Ok(Some(_)), Err(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+fn apply_ops(mut f: impl FnMut(u32, Option<u32>)) {
+    f(0, Some(1));
+    f(1, Some(2));
+    f(0, Some(3));
+    f(2, Some(4));
+    f(1, None);
+    f(3, None);
+    f(4, Some(5));
+    f(4, None);
+    f(4, Some(6));
+}
+
+#[test]
+fn test_update_and_get_or() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(count::invoke(&e, &contract_id), 0);
+    assert_eq!(count_or::invoke(&e, &contract_id, &7), 7);
+    assert!(try_get::invoke(&e, &contract_id));
+
+    assert_eq!(incr::invoke(&e, &contract_id), 1);
+    assert_eq!(incr::invoke(&e, &contract_id), 2);
+    assert_eq!(count::invoke(&e, &contract_id), 2);
+    assert_eq!(count_or::invoke(&e, &contract_id, &7), 2);
+    assert!(try_get::invoke(&e, &contract_id));
+}
+
+#[test]
+fn test_take() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(take::invoke(&e, &contract_id), None);
+    incr::invoke(&e, &contract_id);
+    assert_eq!(take::invoke(&e, &contract_id), Some(1));
+    assert_eq!(take::invoke(&e, &contract_id), None);
+    assert_eq!(count::invoke(&e, &contract_id), 0);
+}
+
+#[test]
+fn test_cached_matches_uncached() {
+    let e = Env::default();
+    let cached_id = BytesN::from_array(&e, [0; 32]);
+    let uncached_id = BytesN::from_array(&e, [1; 32]);
+    e.register_contract(&cached_id, Contract);
+    e.register_contract(&uncached_id, Contract);
+
+    ops::invoke(&e, &cached_id, &true);
+    ops::invoke(&e, &uncached_id, &false);
+    let cached = dump::invoke(&e, &cached_id);
+    let uncached = dump::invoke(&e, &uncached_id);
+    assert_eq!(cached, uncached);
+    assert_eq!(cached.get_unchecked(0), Ok(Some(3)));
+    assert_eq!(cached.get_unchecked(1), Ok(None));
+}
+
+#[test]
+fn test_cached_commit() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let seen = uncommitted::invoke(&e, &contract_id);
+    assert_eq!(
+        seen,
+        Vec::from_array(&e, [true, false, true, false, true, false])
+    );
+}
+
+#[test]
+fn test_cached_commits_on_drop() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(dropped::invoke(&e, &contract_id), 5);
+    assert_eq!(count::invoke(&e, &contract_id), 5);
+}
+
+#[test]
+fn test_cached_overflow() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let vals = overflow::invoke(&e, &contract_id);
+    assert_eq!(vals, Vec::from_array(&e, [99, 10, 20, 30]));
+}
+
+#[test]
+fn test_cached_overflow_preserves_order() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    // The third write does not fit in the cache, and so the first two are
+    // committed before it, and no write becomes visible before an earlier one.
+    let vals = overflow_order::invoke(&e, &contract_id);
+    assert_eq!(
+        vals,
+        Vec::from_array(&e, [99, 99, 99, 99, 99, 99, 0, 10, 99])
+    );
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, contractstorage, Account, BigInt, BytesN, Env, IntoVal};
+
+#[contractstorage]
+pub enum DataKey {
+    #[value(BigInt)]
+    Balance(Account),
+    #[value(Account)]
+    Admin,
+    #[value(u32)]
+    TotalCount,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn init(env: Env, admin: Account) {
+        DataKey::set_admin(&env, &admin);
+        DataKey::set_total_count(&env, &0);
+    }
+
+    pub fn admin(env: Env) -> Option<Account> {
+        DataKey::get_admin(&env)
+    }
+
+    pub fn deposit(env: Env, to: Account, amount: BigInt) {
+        let balance = DataKey::get_balance(&env, &to).unwrap_or_else(|| BigInt::zero(&env));
+        if !DataKey::has_balance(&env, &to) {
+            let count = DataKey::get_total_count(&env).unwrap();
+            DataKey::set_total_count(&env, &(count + 1));
+        }
+        DataKey::set_balance(&env, &to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, of: Account) -> BigInt {
+        DataKey::get_balance(&env, &of).unwrap_or_else(|| BigInt::zero(&env))
+    }
+
+    pub fn count(env: Env) -> u32 {
+        DataKey::get_total_count(&env).unwrap()
+    }
+
+    pub fn clear(env: Env, of: Account) {
+        DataKey::remove_balance(&env, &of);
+    }
+}
+
+#[test]
+fn test_contractstorage() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let admin: Account = [1; 32].into_val(&e);
+    let user: Account = [2; 32].into_val(&e);
+
+    assert_eq!(admin::invoke(&e, &contract_id), None);
+    init::invoke(&e, &contract_id, &admin);
+    assert_eq!(admin::invoke(&e, &contract_id), Some(admin.clone()));
+
+    deposit::invoke(&e, &contract_id, &user, &BigInt::from_u64(&e, 5));
+    deposit::invoke(&e, &contract_id, &user, &BigInt::from_u64(&e, 7));
+    deposit::invoke(&e, &contract_id, &admin, &BigInt::from_u64(&e, 1));
+    assert_eq!(balance::invoke(&e, &contract_id, &user), 12);
+    assert_eq!(count::invoke(&e, &contract_id), 2);
+
+    clear::invoke(&e, &contract_id, &user);
+    assert_eq!(balance::invoke(&e, &contract_id, &user), 0);
+}
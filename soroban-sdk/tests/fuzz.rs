@@ -0,0 +1,110 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, contracttype, testutils::fuzz::ContractFuzzer, Identifier, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum Op {
+    Add(u32),
+    Double,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn apply(v: u32, ops: Vec<Op>) -> u64 {
+        let mut v = v as u64;
+        for op in ops.iter_unchecked() {
+            v = match op {
+                Op::Add(x) => v.saturating_add(x as u64),
+                Op::Double => v.saturating_mul(2),
+            };
+        }
+        v
+    }
+}
+
+#[contracttype(version = 2)]
+#[derive(Clone)]
+pub struct Limits {
+    pub max: u32,
+}
+
+pub struct IdentifierContract;
+
+#[contractimpl]
+impl IdentifierContract {
+    pub fn limit(id: Identifier, limits: Limits) -> u32 {
+        if id.is_contract() {
+            limits.max
+        } else {
+            0
+        }
+    }
+}
+
+mod user {
+    use soroban_sdk::{contractimpl, contracttype};
+
+    // A contract type with the same name as the SDK's Account.
+    #[contracttype]
+    #[derive(Clone)]
+    pub struct Account {
+        pub balance: u32,
+    }
+
+    pub struct AccountContract;
+
+    #[contractimpl]
+    impl AccountContract {
+        pub fn balance(account: Account) -> u32 {
+            account.balance
+        }
+    }
+}
+
+pub struct PanicContract;
+
+#[contractimpl]
+impl PanicContract {
+    pub fn fail(v: u32) -> u32 {
+        panic!("fail with {}", v)
+    }
+}
+
+#[test]
+fn test_fuzz() {
+    for seed in 0..16u8 {
+        let data: std::vec::Vec<u8> = (0..128u8).map(|i| i.wrapping_mul(seed)).collect();
+        ContractFuzzer::new(Contract)
+            .with_type_spec(&__SPEC_XDR_OP)
+            .run(&data);
+    }
+}
+
+#[test]
+fn test_fuzz_identifier_and_versioned() {
+    for seed in 0..16u8 {
+        let data: std::vec::Vec<u8> = (0..128u8).map(|i| i.wrapping_mul(seed)).collect();
+        ContractFuzzer::new(IdentifierContract)
+            .with_versioned_type_spec::<Limits>(&__SPEC_XDR_LIMITS)
+            .run(&data);
+    }
+}
+
+#[test]
+fn test_fuzz_type_named_as_sdk_type() {
+    for seed in 0..16u8 {
+        let data: std::vec::Vec<u8> = (0..128u8).map(|i| i.wrapping_mul(seed)).collect();
+        ContractFuzzer::new(user::AccountContract)
+            .with_type_spec(&user::__SPEC_XDR_ACCOUNT)
+            .run(&data);
+    }
+}
+
+#[test]
+#[should_panic(expected = "fail with")]
+fn test_fuzz_reports_panic() {
+    ContractFuzzer::new(PanicContract).run(&[0, 1, 2, 3, 4, 5, 6, 7]);
+}
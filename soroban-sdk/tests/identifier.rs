@@ -0,0 +1,84 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, Account, BytesN, Env, Identifier, IntoVal};
+
+pub struct Inner;
+
+#[contractimpl]
+impl Inner {
+    pub fn whoami(env: Env) -> Identifier {
+        env.invoker()
+    }
+
+    pub fn contract(env: Env) -> Option<BytesN<32>> {
+        env.invoking_contract()
+    }
+}
+
+pub struct Outer;
+
+#[contractimpl]
+impl Outer {
+    pub fn call(env: Env, inner: BytesN<32>) -> Identifier {
+        whoami::invoke(&env, &inner)
+    }
+
+    pub fn call_contract(env: Env, inner: BytesN<32>) -> Option<BytesN<32>> {
+        contract::invoke(&env, &inner)
+    }
+
+    pub fn echo(id: Identifier) -> Identifier {
+        id
+    }
+}
+
+#[test]
+fn test_invoker_contract() {
+    let e = Env::default();
+    let inner_id = BytesN::from_array(&e, [0; 32]);
+    let outer_id = BytesN::from_array(&e, [1; 32]);
+    e.register_contract(&inner_id, Inner);
+    e.register_contract(&outer_id, Outer);
+
+    let invoker = call::invoke(&e, &outer_id, &inner_id);
+    assert_eq!(invoker, Identifier::Contract(outer_id.clone()));
+
+    let invoking_contract = call_contract::invoke(&e, &outer_id, &inner_id);
+    assert_eq!(invoking_contract, Some(outer_id));
+}
+
+#[test]
+fn test_identifier_arg() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [1; 32]);
+    e.register_contract(&contract_id, Outer);
+
+    let id = Identifier::Ed25519(BytesN::from_array(&e, [2; 32]));
+    assert_eq!(echo::invoke(&e, &contract_id, &id), id);
+}
+
+#[test]
+fn test_invoker_account() {
+    let e = Env::default();
+    let inner_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&inner_id, Inner);
+
+    let source: Account = [3; 32].into_val(&e);
+    e.set_source_account(&BytesN::from_array(&e, [3; 32]));
+
+    let invoker = whoami::invoke(&e, &inner_id);
+    assert_eq!(invoker, Identifier::Account(source));
+    assert_eq!(contract::invoke(&e, &inner_id), None);
+}
+
+#[test]
+fn test_spec_included_by_referencing_functions() {
+    assert_eq!(
+        __SPEC_XDR_WHOAMI_IDENTIFIER,
+        soroban_sdk::__SPEC_XDR_IDENTIFIER
+    );
+    assert_eq!(
+        __SPEC_XDR_ECHO_IDENTIFIER,
+        soroban_sdk::__SPEC_XDR_IDENTIFIER
+    );
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{bytesn, BytesN, Env};
+
+#[test]
+fn test_bytesn() {
+    let e = Env::default();
+
+    let b: BytesN<2> = bytesn!(&e, 0xabcd);
+    assert_eq!(b, BytesN::from_array(&e, [0xab, 0xcd]));
+
+    let b: BytesN<3> = bytesn!(&e, 0x00_01_ff);
+    assert_eq!(b, BytesN::from_array(&e, [0x00, 0x01, 0xff]));
+
+    let b = bytesn!(
+        &e,
+        0x363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103
+    );
+    assert_eq!(b.len(), 32);
+    assert_eq!(
+        format!("{}", b),
+        "363eaa3867841fbad0f4ed88c779e4fe66e56a2470dc98c0ec9c073d05c7b103"
+    );
+}
@@ -0,0 +1,7 @@
+use soroban_sdk::{bytesn, Env};
+
+pub fn bytes(e: &Env) {
+    bytesn!(e, 0xabc);
+}
+
+pub fn main() {}
@@ -0,0 +1,7 @@
+use soroban_sdk::{symbol, Symbol};
+
+pub fn name() -> Symbol {
+    symbol!("abcdefghijk")
+}
+
+pub fn main() {}
@@ -0,0 +1,15 @@
+use soroban_sdk::prelude::*;
+use soroban_sdk::{symbol, Bytes, Env, Symbol};
+
+#[test]
+fn test_symbol() {
+    let e = Env::default();
+
+    assert_eq!(symbol!(""), Symbol::from_str(""));
+    assert_eq!(symbol!("hello"), Symbol::from_str("hello"));
+    assert_eq!(symbol!("Abc_019xyZ"), Symbol::from_str("Abc_019xyZ"));
+
+    let s = symbol!("transfer");
+    assert_eq!(s.to_bytes(&e), Bytes::from_slice(&e, b"transfer"));
+    assert_eq!(Symbol::try_from_bytes(&s.to_bytes(&e)), Ok(s));
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, BytesN, Env, StorageMap, StorageVec, Symbol, Vec};
+
+pub struct Contract;
+
+fn map(env: &Env) -> StorageMap<u32, i64> {
+    StorageMap::new(env, Symbol::from_str("map"))
+}
+
+fn vec(env: &Env) -> StorageVec<u32> {
+    StorageVec::new(env, Symbol::from_str("vec"))
+}
+
+#[contractimpl]
+impl Contract {
+    pub fn map_set(env: Env, k: u32, v: i64) {
+        map(&env).set(&k, &v);
+    }
+
+    pub fn map_get(env: Env, k: u32) -> Option<i64> {
+        map(&env).get(&k)
+    }
+
+    pub fn map_remove(env: Env, k: u32) -> Option<i64> {
+        map(&env).remove(&k)
+    }
+
+    pub fn map_len(env: Env) -> u32 {
+        map(&env).len()
+    }
+
+    pub fn map_keys(env: Env) -> Vec<u32> {
+        let mut keys = Vec::new(&env);
+        for (k, v) in map(&env).iter() {
+            assert_eq!(map(&env).get(&k), Some(v));
+            keys.push(k);
+        }
+        keys
+    }
+
+    pub fn vec_push(env: Env, v: u32) {
+        vec(&env).push(&v);
+    }
+
+    pub fn vec_pop(env: Env) -> Option<u32> {
+        vec(&env).pop()
+    }
+
+    pub fn vec_remove(env: Env, i: u32) -> Option<u32> {
+        vec(&env).remove(i)
+    }
+
+    pub fn vec_swap_remove(env: Env, i: u32) -> Option<u32> {
+        vec(&env).swap_remove(i)
+    }
+
+    pub fn vec_all(env: Env) -> Vec<u32> {
+        let mut all = Vec::new(&env);
+        for v in vec(&env).iter() {
+            all.push(v);
+        }
+        all
+    }
+}
+
+#[test]
+fn test_storage_map() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(map_len::invoke(&e, &contract_id), 0);
+    assert_eq!(map_get::invoke(&e, &contract_id, &1), None);
+
+    map_set::invoke(&e, &contract_id, &1, &10);
+    map_set::invoke(&e, &contract_id, &2, &20);
+    map_set::invoke(&e, &contract_id, &3, &30);
+    map_set::invoke(&e, &contract_id, &2, &21);
+    assert_eq!(map_len::invoke(&e, &contract_id), 3);
+    assert_eq!(map_get::invoke(&e, &contract_id, &2), Some(21));
+    assert_eq!(
+        map_keys::invoke(&e, &contract_id),
+        Vec::from_array(&e, [1, 2, 3])
+    );
+
+    assert_eq!(map_remove::invoke(&e, &contract_id, &1), Some(10));
+    assert_eq!(map_remove::invoke(&e, &contract_id, &1), None);
+    assert_eq!(map_len::invoke(&e, &contract_id), 2);
+    assert_eq!(map_get::invoke(&e, &contract_id, &1), None);
+    assert_eq!(map_get::invoke(&e, &contract_id, &3), Some(30));
+    assert_eq!(
+        map_keys::invoke(&e, &contract_id),
+        Vec::from_array(&e, [3, 2])
+    );
+
+    assert_eq!(map_remove::invoke(&e, &contract_id, &2), Some(21));
+    assert_eq!(map_remove::invoke(&e, &contract_id, &3), Some(30));
+    assert_eq!(map_len::invoke(&e, &contract_id), 0);
+}
+
+#[test]
+fn test_storage_vec() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(vec_pop::invoke(&e, &contract_id), None);
+    for v in [1, 2, 3, 4] {
+        vec_push::invoke(&e, &contract_id, &v);
+    }
+    assert_eq!(
+        vec_all::invoke(&e, &contract_id),
+        Vec::from_array(&e, [1, 2, 3, 4])
+    );
+
+    assert_eq!(vec_remove::invoke(&e, &contract_id, &1), Some(2));
+    assert_eq!(
+        vec_all::invoke(&e, &contract_id),
+        Vec::from_array(&e, [1, 3, 4])
+    );
+
+    assert_eq!(vec_swap_remove::invoke(&e, &contract_id, &0), Some(1));
+    assert_eq!(
+        vec_all::invoke(&e, &contract_id),
+        Vec::from_array(&e, [4, 3])
+    );
+
+    assert_eq!(vec_remove::invoke(&e, &contract_id, &2), None);
+    assert_eq!(vec_swap_remove::invoke(&e, &contract_id, &2), None);
+    assert_eq!(vec_pop::invoke(&e, &contract_id), Some(3));
+    assert_eq!(vec_pop::invoke(&e, &contract_id), Some(4));
+    assert_eq!(vec_pop::invoke(&e, &contract_id), None);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "testutils")]
+
+use std::io::Cursor;
+
+use soroban_sdk::{
+    contractimpl, contracttype, vec, Bytes, BytesN, Env, EnvVal, IntoVal, String, Symbol, Vec,
+};
+use stellar_xdr::{ReadXdr, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef, ScSpecTypeUdt};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Metadata {
+    pub name: String,
+    pub uri: String,
+}
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn greet(env: Env, name: String) -> String {
+        let mut s = String::from_str(&env, "hello, ");
+        s.append(&name);
+        s
+    }
+
+    pub fn name(metadata: Metadata) -> soroban_sdk::String {
+        metadata.name
+    }
+}
+
+#[test]
+fn test_string_args() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let s = greet::invoke(&e, &contract_id, &"wörld".into_val(&e));
+    assert_eq!(s, String::from_str(&e, "hello, wörld"));
+
+    let metadata = Metadata {
+        name: String::from_str(&e, "name"),
+        uri: String::from_str(&e, "https://example.com"),
+    };
+    assert_eq!(
+        name::invoke(&e, &contract_id, &metadata),
+        String::from_str(&e, "name")
+    );
+}
+
+#[test]
+fn test_spec_imported() {
+    let entries = ScSpecEntry::read_xdr(&mut Cursor::new(&__SPEC_XDR_GREET)).unwrap();
+    let expect = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        name: "greet".try_into().unwrap(),
+        input_types: std::vec![ScSpecTypeDef::Binary].try_into().unwrap(),
+        output_types: std::vec![ScSpecTypeDef::Binary].try_into().unwrap(),
+    });
+    assert_eq!(entries, expect);
+}
+
+#[test]
+fn test_spec_full_path() {
+    let entries = ScSpecEntry::read_xdr(&mut Cursor::new(&__SPEC_XDR_NAME)).unwrap();
+    let expect = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        name: "name".try_into().unwrap(),
+        input_types: std::vec![ScSpecTypeDef::Udt(ScSpecTypeUdt {
+            name: "Metadata".try_into().unwrap(),
+        })]
+        .try_into()
+        .unwrap(),
+        output_types: std::vec![ScSpecTypeDef::Binary].try_into().unwrap(),
+    });
+    assert_eq!(entries, expect);
+}
+
+#[test]
+fn test_spec_type_fields() {
+    let entries = ScSpecEntry::read_xdr(&mut Cursor::new(&__SPEC_XDR_METADATA)).unwrap();
+    let fields = match entries {
+        ScSpecEntry::UdtStructV0(s) => s.fields,
+        _ => panic!("expected struct spec"),
+    };
+    assert!(fields.iter().all(|f| f.type_ == ScSpecTypeDef::Binary));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_utf8_arg() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let invalid = Bytes::from_slice(&e, &[0xff]);
+    let args: Vec<EnvVal> = vec![&e, invalid.into()];
+    e.invoke_contract::<String>(&contract_id, &Symbol::from_str("greet"), args);
+}
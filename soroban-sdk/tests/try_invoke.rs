@@ -0,0 +1,63 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{contractimpl, vec, BytesN, Env, EnvVal, IntoVal, Status, Symbol};
+use stellar_xdr::{ScHostValErrorCode, ScStatusType};
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    pub fn fail(code: u32) -> Status {
+        Status::from_type_and_code(ScStatusType::ContractError, code)
+    }
+}
+
+#[test]
+fn test_try_invoke_ok() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let c = add::try_invoke(&e, &contract_id, &10, &12);
+    assert_eq!(c, Ok(22));
+}
+
+#[test]
+fn test_try_invoke_missing_function() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let res: Result<i32, Status> =
+        e.try_invoke_contract(&contract_id, &Symbol::from_str("sub"), vec![&e]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_try_invoke_conversion_error() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let args: soroban_sdk::Vec<EnvVal> = vec![&e, 10i32.into_env_val(&e), 12i32.into_env_val(&e)];
+    let res: Result<BytesN<32>, Status> =
+        e.try_invoke_contract(&contract_id, &Symbol::from_str("add"), args);
+    assert_eq!(res, Err(ScHostValErrorCode::UnexpectedValType.into()));
+}
+
+#[test]
+fn test_try_invoke_contract_status() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    let res = fail::try_invoke(&e, &contract_id, &7);
+    assert_eq!(
+        res,
+        Err(Status::from_type_and_code(ScStatusType::ContractError, 7))
+    );
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "testutils")]
+
+use soroban_sdk::{
+    contractimpl, contracttype, BytesN, Env, IntoVal, Migrate, RawVal, Symbol, TryIntoVal,
+    Versioned,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unversioned {
+    pub a: u32,
+}
+
+#[contracttype(version = 1)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigV1 {
+    pub a: u32,
+}
+
+#[contracttype(version = 2)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    pub a: u32,
+    pub b: i64,
+}
+
+impl Migrate for Config {
+    type Previous = ConfigV1;
+
+    fn migrate(_env: &Env, prev: ConfigV1) -> Self {
+        Config { a: prev.a, b: -1 }
+    }
+}
+
+#[contracttype(version = 3)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigV3 {
+    pub a: u32,
+}
+
+const KEY: Symbol = Symbol::from_str("config");
+
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn set_v1(env: Env, a: u32) {
+        env.contract_data().set(KEY, Unversioned { a });
+    }
+
+    pub fn set_v3(env: Env, a: u32) {
+        env.contract_data().set(KEY, ConfigV3 { a });
+    }
+
+    pub fn get(env: Env, rewrite: bool) -> Option<Config> {
+        env.contract_data().get_migrating(KEY, rewrite).unwrap()
+    }
+
+    pub fn get_err(env: Env) -> bool {
+        env.contract_data()
+            .get_migrating::<_, Config>(KEY, false)
+            .is_err()
+    }
+
+    pub fn is_v2(env: Env) -> bool {
+        env.contract_data().try_get::<_, Config>(KEY).is_ok()
+    }
+}
+
+#[test]
+fn test_versions() {
+    assert_eq!(ConfigV1::VERSION, 1);
+    assert_eq!(Config::VERSION, 2);
+}
+
+#[test]
+fn test_roundtrip() {
+    let e = Env::default();
+    let c = Config { a: 1, b: 2 };
+    let val: RawVal = c.clone().into_val(&e);
+    let rt: Config = val.try_into_val(&e).unwrap();
+    assert_eq!(rt, c);
+
+    // A different version does not convert.
+    let res: Result<ConfigV1, _> = val.try_into_val(&e);
+    assert!(res.is_err());
+
+    // A value stored without a version converts as version one.
+    let val: RawVal = Unversioned { a: 1 }.into_val(&e);
+    let v1: ConfigV1 = val.try_into_val(&e).unwrap();
+    assert_eq!(v1, ConfigV1 { a: 1 });
+    let res: Result<Config, _> = val.try_into_val(&e);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_get_migrating() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    assert_eq!(get::invoke(&e, &contract_id, &false), None);
+
+    set_v1::invoke(&e, &contract_id, &5);
+    assert!(!is_v2::invoke(&e, &contract_id));
+    assert_eq!(
+        get::invoke(&e, &contract_id, &false),
+        Some(Config { a: 5, b: -1 })
+    );
+    assert!(!is_v2::invoke(&e, &contract_id));
+
+    assert_eq!(
+        get::invoke(&e, &contract_id, &true),
+        Some(Config { a: 5, b: -1 })
+    );
+    assert!(is_v2::invoke(&e, &contract_id));
+    assert_eq!(
+        get::invoke(&e, &contract_id, &false),
+        Some(Config { a: 5, b: -1 })
+    );
+}
+
+#[test]
+fn test_get_migrating_unknown_version() {
+    let e = Env::default();
+    let contract_id = BytesN::from_array(&e, [0; 32]);
+    e.register_contract(&contract_id, Contract);
+
+    set_v3::invoke(&e, &contract_id, &5);
+    assert!(get_err::invoke(&e, &contract_id));
+}
+
+#[test]
+fn test_spec_includes_version() {
+    use soroban_sdk::xdr::{
+        ReadXdr, ScMap, ScMapEntry, ScObject, ScSpecEntry, ScSpecTypeDef, ScVal,
+    };
+    use soroban_sdk::Object;
+
+    let mut cursor = std::io::Cursor::new(&__SPEC_XDR_CONFIGV3[..]);
+    let spec = match ScSpecEntry::read_xdr(&mut cursor).unwrap() {
+        ScSpecEntry::UdtStructV0(s) => s,
+        _ => panic!("expected struct spec"),
+    };
+
+    // Build a value the way a client following the spec would.
+    let e = Env::default();
+    let mut entries = std::vec::Vec::new();
+    for f in spec.fields.iter() {
+        let name = f.name.to_string().unwrap();
+        let val = match (&name[..], &f.type_) {
+            ("_version", ScSpecTypeDef::U32) => ScVal::U32(ConfigV3::VERSION),
+            (_, ScSpecTypeDef::U32) => ScVal::U32(7),
+            _ => panic!("unexpected field {}", name),
+        };
+        entries.push(ScMapEntry {
+            key: name.as_str().try_into().unwrap(),
+            val,
+        });
+    }
+    assert_eq!(entries.len(), 2);
+    let val = ScVal::Object(Some(ScObject::Map(ScMap::sorted_from(entries).unwrap())));
+    let obj: Object = val.try_into_val(&e).unwrap();
+    let c: ConfigV3 = RawVal::from(obj).try_into_val(&e).unwrap();
+    assert_eq!(c, ConfigV3 { a: 7 });
+}
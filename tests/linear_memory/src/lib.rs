@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contractimpl, Bytes, Env};
+use soroban_sdk::{contractimpl, Bytes, BytesN, Env};
 
 pub struct Contract;
 
@@ -26,4 +26,47 @@ impl Contract {
             assert!(buf[idx as usize] == b.get_unchecked(b_pos + idx));
         }
     }
+
+    pub fn into_slice(b: Bytes) -> u32 {
+        let mut buf = [0u8; 4];
+        b.copy_into_slice(&mut buf);
+        u32::from_be_bytes(buf)
+    }
+
+    pub fn from_slice(mut b: Bytes, b_pos: u32) -> Bytes {
+        b.copy_from_slice(b_pos, &[0, 1, 2, 3]);
+        b
+    }
+
+    pub fn to_array(b: BytesN<4>) -> u32 {
+        u32::from_be_bytes(b.to_array())
+    }
+}
+
+// The tests run the contract natively only. The workspace wasm build checks
+// that the slice copies compile for wasm, but there is no harness in this
+// repository that runs a built wasm contract in the host VM, and so the wasm
+// path of the slice copies is not tested.
+#[cfg(test)]
+mod test {
+    use soroban_sdk::{bin, BytesN, Env};
+
+    use crate::{from_slice, into_slice, to_array, Contract};
+
+    #[test]
+    fn test_slices() {
+        let e = Env::default();
+        let contract_id = BytesN::from_array(&e, [0; 32]);
+        e.register_contract(&contract_id, Contract);
+
+        let b = bin![&e, 1, 2, 3, 4, 5];
+        assert_eq!(into_slice::invoke(&e, &contract_id, &b), 0x01020304);
+        assert_eq!(
+            from_slice::invoke(&e, &contract_id, &b, &2),
+            bin![&e, 1, 2, 0, 1, 2, 3]
+        );
+
+        let b = BytesN::from_array(&e, [4, 3, 2, 1]);
+        assert_eq!(to_array::invoke(&e, &contract_id, &b), 0x04030201);
+    }
 }
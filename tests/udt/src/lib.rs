@@ -185,4 +185,19 @@ mod test {
         let roundtrip = UdtStruct::try_from_val(&e, val).unwrap();
         assert_eq!(udt, roundtrip);
     }
+
+    #[test]
+    fn test_arbitrary() {
+        use soroban_sdk::testutils::arbitrary::{ArbitraryVal, Unstructured};
+        let e = Env::default();
+        let contract_id = BytesN::from_array(&e, [0; 32]);
+        e.register_contract(&contract_id, Contract);
+
+        let data = [1u8; 64];
+        let mut u = Unstructured::new(&data);
+        let a = UdtEnum::arbitrary_val(&e, &mut u).unwrap();
+        let b = UdtEnum::arbitrary_val(&e, &mut u).unwrap();
+        let z = add::invoke(&e, &contract_id, &a, &b);
+        assert_eq!(z, Contract::add(a, b));
+    }
 }